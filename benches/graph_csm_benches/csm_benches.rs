@@ -1,6 +1,7 @@
 use criterion::{Criterion, criterion_group};
 use next_graph::{
-    CsmGraph, DynamicGraph, Freezable, GraphAlgorithms, GraphMut, GraphView, Unfreezable,
+    CsmGraph, DynamicGraph, Freezable, GraphAlgorithms, GraphMut, GraphView, ReachabilityIndex,
+    TraversalWorkspace, Unfreezable,
 };
 use std::hint::black_box;
 
@@ -94,6 +95,19 @@ fn bench_csm_graph(c: &mut Criterion) {
     algo_group.bench_function("find_cycle (has cycle, 1k nodes)", |b| {
         b.iter(|| black_box(cyclic_graph.find_cycle()))
     });
+
+    // Reachability: a fresh BFS per query versus a prebuilt index.
+    let reachability_index = ReachabilityIndex::new(&chain_graph);
+    algo_group.bench_function("is_reachable (BFS, 1k nodes)", |b| {
+        b.iter(|| black_box(chain_graph.is_reachable(0, 999)))
+    });
+    let mut workspace = TraversalWorkspace::with_capacity(1_000);
+    algo_group.bench_function("is_reachable (TraversalWorkspace, 1k nodes)", |b| {
+        b.iter(|| black_box(chain_graph.is_reachable_with_workspace(0, 999, &mut workspace)))
+    });
+    algo_group.bench_function("is_reachable (ReachabilityIndex, 1k nodes)", |b| {
+        b.iter(|| black_box(reachability_index.is_reachable(0, 999)))
+    });
     algo_group.finish();
 
     // --- Group 3: Large-Scale Algorithms (Cache-Stressed) ---
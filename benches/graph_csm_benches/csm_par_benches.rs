@@ -43,6 +43,16 @@ pub fn bench_csm_graph_par(c: &mut Criterion) {
         b.iter(|| black_box(large_graph.shortest_path_par(0, 999_999)))
     });
 
+    large_par_algo_group.bench_function(
+        "strongly_connected_components_par (1M nodes, 5M edges)",
+        |b| b.iter(|| black_box(large_graph.strongly_connected_components_par())),
+    );
+
+    large_par_algo_group.bench_function(
+        "weakly_connected_components_par (1M nodes, 5M edges)",
+        |b| b.iter(|| black_box(large_graph.weakly_connected_components_par())),
+    );
+
     large_par_algo_group.finish();
 }
 
@@ -1,7 +1,7 @@
 // In /benches/graph_dyn_benches/dyn_benches.rs
 
 use criterion::{Criterion, criterion_group};
-use next_graph::{DynamicGraph, GraphMut, GraphView};
+use next_graph::{DynamicGraph, GraphAlgorithms, GraphMut, GraphView};
 use std::hint::black_box;
 
 /// Helper to create a graph with a "hub" node connected to many "spoke" nodes.
@@ -101,6 +101,16 @@ pub fn bench_dyn_graph(c: &mut Criterion) {
         })
     });
     lookup_group.finish();
+
+    // --- Group 4: Algorithms ---
+    let mut algo_group = c.benchmark_group("DynamicGraph Algorithms");
+
+    // A single high-degree hub checks that the cycle search stays linear in the degree.
+    let hub_graph = create_hub_graph(40_000);
+    algo_group.bench_function("has_cycle (hub, 40k neighbors)", |b| {
+        b.iter(|| black_box(hub_graph.has_cycle()))
+    });
+    algo_group.finish();
 }
 
 criterion_group!(dyn_benches, bench_dyn_graph);
@@ -0,0 +1,104 @@
+use crate::types::traversal_workspace::NO_PREDECESSOR;
+use crate::{GraphTraversal, TraversalWorkspace};
+
+/// Finds a single cycle in the graph and returns the path of nodes that form it.
+///
+/// The cycle is returned as a closed path that starts and ends with the same node
+/// (e.g., `[1, 2, 0, 1]`); a self-loop on node `n` is returned as `[n, n]`.
+/// Returns `None` if the graph is a DAG.
+///
+/// # Complexity
+///
+/// - **Time Complexity:** O(V + E).
+/// - **Space Complexity:** O(V) for the traversal state and predecessors.
+pub fn find_cycle<G, N, W>(graph: &G) -> Option<Vec<usize>>
+where
+    G: GraphTraversal<N, W>,
+{
+    find_cycle_with_workspace(graph, &mut TraversalWorkspace::new())
+}
+
+/// Finds a single cycle like `find_cycle`, using the buffers of the given workspace.
+///
+/// Apart from the returned path, this does not allocate once the workspace buffers
+/// have grown to the size of the graph.
+pub fn find_cycle_with_workspace<G, N, W>(
+    graph: &G,
+    workspace: &mut TraversalWorkspace,
+) -> Option<Vec<usize>>
+where
+    G: GraphTraversal<N, W>,
+{
+    let bound = graph.node_index_bound();
+    workspace.begin(bound);
+
+    // A node is "in progress" while it is visited but not yet finished.
+    for i in 0..bound {
+        if !graph.contains_node(i) || workspace.is_visited(i) {
+            continue;
+        }
+
+        workspace.visit(i, NO_PREDECESSOR);
+        push_frame(graph, workspace, i);
+
+        // The successors of every frame are buffered in reverse order, so the
+        // unexplored successors of the topmost frame form the tail of the buffer.
+        while let Some(&(u, start)) = workspace.stack.last() {
+            if workspace.successors.len() == start {
+                // If all edges are explored, we are done with this node.
+                workspace.finish(u);
+                workspace.stack.pop();
+                continue;
+            }
+            // The unwrap is safe because the buffer is longer than `start`.
+            let v = workspace.successors.pop().unwrap();
+
+            if workspace.is_visited(v) && !workspace.is_finished(v) {
+                // --- Cycle Found: Reconstruct the Path ---
+                let mut path = vec![u];
+                let mut current = u;
+                while let Some(predecessor) = workspace.predecessor(current) {
+                    if current == v {
+                        break;
+                    }
+                    path.push(predecessor);
+                    current = predecessor;
+                }
+                path.reverse();
+                path.push(v); // Make the cycle explicit: [v, ..., u, v]
+                return Some(path);
+            }
+
+            if !workspace.is_visited(v) {
+                workspace.visit(v, u);
+                push_frame(graph, workspace, v);
+            }
+        }
+    }
+
+    None // No cycles found after checking all nodes.
+}
+
+/// Pushes a DFS frame for `node` and buffers its successors in reverse order.
+///
+/// Buffering the successors once keeps the traversal O(V + E) for any
+/// `GraphTraversal`, since its successor iterators cannot be resumed.
+fn push_frame<G, N, W>(graph: &G, workspace: &mut TraversalWorkspace, node: usize)
+where
+    G: GraphTraversal<N, W>,
+{
+    let start = workspace.successors.len();
+    if let Ok(successors) = graph.outbound_edges(node) {
+        workspace.successors.extend(successors);
+    }
+    workspace.successors[start..].reverse();
+    workspace.stack.push((node, start));
+}
+
+/// Checks if the graph contains any directed cycles.
+pub fn has_cycle<G, N, W>(graph: &G) -> bool
+where
+    G: GraphTraversal<N, W>,
+{
+    find_cycle(graph).is_some()
+}
@@ -0,0 +1,14 @@
+mod cycle;
+mod path;
+mod topological_sort;
+
+// Generic graph algorithms, written once against `GraphTraversal` (and therefore
+// `GraphView`). Any graph storage that implements these traits gets them for free.
+// `CsmGraph` keeps its own specialized implementations that read the CSR arrays directly.
+
+pub use cycle::{find_cycle, find_cycle_with_workspace, has_cycle};
+pub use path::{
+    is_reachable, shortest_path, shortest_path_len, shortest_path_len_with_workspace,
+    shortest_path_with_workspace,
+};
+pub use topological_sort::{topological_sort, topological_sort_by, topological_sort_by_key};
@@ -0,0 +1,122 @@
+use crate::types::traversal_workspace::NO_PREDECESSOR;
+use crate::{GraphTraversal, TraversalWorkspace};
+
+/// Checks if a path of any length exists from a start to a stop index.
+///
+/// Every valid node reaches itself. Returns `false` if either index is invalid.
+pub fn is_reachable<G, N, W>(graph: &G, start_index: usize, stop_index: usize) -> bool
+where
+    G: GraphTraversal<N, W>,
+{
+    shortest_path_len(graph, start_index, stop_index).is_some()
+}
+
+/// Returns the length of the shortest path (in number of nodes) from a start to a stop index.
+pub fn shortest_path_len<G, N, W>(graph: &G, start_index: usize, stop_index: usize) -> Option<usize>
+where
+    G: GraphTraversal<N, W>,
+{
+    shortest_path_len_with_workspace(
+        graph,
+        start_index,
+        stop_index,
+        &mut TraversalWorkspace::new(),
+    )
+}
+
+/// Returns the length of the shortest path like `shortest_path_len`, using the buffers
+/// of the given workspace.
+pub fn shortest_path_len_with_workspace<G, N, W>(
+    graph: &G,
+    start_index: usize,
+    stop_index: usize,
+    workspace: &mut TraversalWorkspace,
+) -> Option<usize>
+where
+    G: GraphTraversal<N, W>,
+{
+    bfs(graph, start_index, stop_index, workspace)
+}
+
+/// Finds the complete shortest path from a start to a stop index using a BFS.
+///
+/// # Complexity
+///
+/// - **Time Complexity:** O(V + E).
+/// - **Space Complexity:** O(V) for the queue and predecessors.
+pub fn shortest_path<G, N, W>(
+    graph: &G,
+    start_index: usize,
+    stop_index: usize,
+) -> Option<Vec<usize>>
+where
+    G: GraphTraversal<N, W>,
+{
+    shortest_path_with_workspace(
+        graph,
+        start_index,
+        stop_index,
+        &mut TraversalWorkspace::new(),
+    )
+}
+
+/// Finds the complete shortest path like `shortest_path`, using the buffers of the
+/// given workspace.
+pub fn shortest_path_with_workspace<G, N, W>(
+    graph: &G,
+    start_index: usize,
+    stop_index: usize,
+    workspace: &mut TraversalWorkspace,
+) -> Option<Vec<usize>>
+where
+    G: GraphTraversal<N, W>,
+{
+    let len = bfs(graph, start_index, stop_index, workspace)?;
+
+    // Reconstruct path by walking backwards from the stop index.
+    let mut path = Vec::with_capacity(len);
+    let mut current = Some(stop_index);
+    while let Some(curr_index) = current {
+        path.push(curr_index);
+        current = workspace.predecessor(curr_index);
+    }
+    path.reverse();
+    Some(path)
+}
+
+/// Runs a BFS from `start_index` until `stop_index` is found, recording predecessors
+/// in the workspace, and returns the path length in number of nodes.
+fn bfs<G, N, W>(
+    graph: &G,
+    start_index: usize,
+    stop_index: usize,
+    workspace: &mut TraversalWorkspace,
+) -> Option<usize>
+where
+    G: GraphTraversal<N, W>,
+{
+    if !graph.contains_node(start_index) || !graph.contains_node(stop_index) {
+        return None;
+    }
+
+    workspace.begin(graph.node_index_bound());
+    workspace.visit(start_index, NO_PREDECESSOR);
+    if start_index == stop_index {
+        return Some(1);
+    }
+    workspace.queue.push_back((start_index, 1)); // (node, path_length)
+
+    while let Some((current_node, current_len)) = workspace.queue.pop_front() {
+        // The unwrap is safe because only valid nodes are enqueued.
+        for neighbor in graph.outbound_edges(current_node).unwrap() {
+            if !workspace.is_visited(neighbor) {
+                workspace.visit(neighbor, current_node);
+                if neighbor == stop_index {
+                    return Some(current_len + 1);
+                }
+                workspace.queue.push_back((neighbor, current_len + 1));
+            }
+        }
+    }
+    None
+}
@@ -0,0 +1,145 @@
+use crate::GraphTraversal;
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, VecDeque};
+
+/// Computes a topological sort of the graph using Kahn's algorithm.
+///
+/// # Returns
+/// - `Some(Vec<usize>)` with all valid node indices in a topological order if the
+///   graph is a Directed Acyclic Graph (DAG).
+/// - `None` if the graph contains a cycle.
+///
+/// # Complexity
+///
+/// - **Time Complexity:** O(V + E).
+/// - **Space Complexity:** O(V) for the in-degrees and the queue.
+pub fn topological_sort<G, N, W>(graph: &G) -> Option<Vec<usize>>
+where
+    G: GraphTraversal<N, W>,
+{
+    let index_bound = graph.node_index_bound();
+    let valid_nodes = || (0..index_bound).filter(|&i| graph.contains_node(i));
+
+    // 1. Compute in-degrees for all valid nodes.
+    let mut in_degrees = vec![0; index_bound];
+    for u in valid_nodes() {
+        // The unwrap is safe because we are dealing with valid node indices.
+        for v in graph.outbound_edges(u).unwrap() {
+            in_degrees[v] += 1;
+        }
+    }
+
+    // 2. Initialize a queue with all nodes that have an in-degree of 0.
+    let mut queue: VecDeque<usize> = valid_nodes().filter(|&i| in_degrees[i] == 0).collect();
+
+    // 3. Process the queue.
+    let mut sorted_list = Vec::with_capacity(index_bound);
+    while let Some(u) = queue.pop_front() {
+        sorted_list.push(u);
+        for v in graph.outbound_edges(u).unwrap() {
+            in_degrees[v] -= 1;
+            if in_degrees[v] == 0 {
+                queue.push_back(v);
+            }
+        }
+    }
+
+    // 4. Validate the result.
+    if sorted_list.len() == valid_nodes().count() {
+        Some(sorted_list)
+    } else {
+        None // Cycle detected
+    }
+}
+
+/// Computes a topological sort that always picks the smallest ready node by `compare`.
+///
+/// Among all nodes whose predecessors are already sorted, the node with the smallest
+/// payload according to `compare` comes next. Nodes with equal payloads are ordered by
+/// their index, so the result only depends on the graph and the comparator.
+///
+/// # Returns
+/// - `Some(Vec<usize>)` with all valid node indices in a topological order if the
+///   graph is a Directed Acyclic Graph (DAG).
+/// - `None` if the graph contains a cycle.
+///
+/// # Complexity
+///
+/// - **Time Complexity:** O(V log V + E) for ranking the nodes and the priority queue.
+/// - **Space Complexity:** O(V) for the ranks, in-degrees and the queue.
+pub fn topological_sort_by<G, N, W, F>(graph: &G, mut compare: F) -> Option<Vec<usize>>
+where
+    G: GraphTraversal<N, W>,
+    F: FnMut(&N, &N) -> Ordering,
+{
+    let mut ranking: Vec<usize> = (0..graph.node_index_bound())
+        .filter(|&i| graph.contains_node(i))
+        .collect();
+    // The sort is stable, so nodes with equal payloads stay ordered by index.
+    ranking.sort_by(|&a, &b| compare(graph.get_node(a).unwrap(), graph.get_node(b).unwrap()));
+
+    prioritized_topological_sort(graph, &ranking)
+}
+
+/// Computes a topological sort that always picks the ready node with the smallest key.
+///
+/// The key of every node is computed exactly once. Nodes with equal keys are ordered
+/// by their index. See `topological_sort_by` for details.
+pub fn topological_sort_by_key<G, N, W, K, F>(graph: &G, mut f: F) -> Option<Vec<usize>>
+where
+    G: GraphTraversal<N, W>,
+    K: Ord,
+    F: FnMut(&N) -> K,
+{
+    let mut ranking: Vec<usize> = (0..graph.node_index_bound())
+        .filter(|&i| graph.contains_node(i))
+        .collect();
+    // The sort is stable, so nodes with equal keys stay ordered by index.
+    ranking.sort_by_cached_key(|&i| f(graph.get_node(i).unwrap()));
+
+    prioritized_topological_sort(graph, &ranking)
+}
+
+/// Runs Kahn's algorithm with a min-heap of ranks instead of a FIFO queue.
+///
+/// `ranking` lists all valid nodes from the highest to the lowest priority.
+fn prioritized_topological_sort<G, N, W>(graph: &G, ranking: &[usize]) -> Option<Vec<usize>>
+where
+    G: GraphTraversal<N, W>,
+{
+    let index_bound = graph.node_index_bound();
+    let mut ranks = vec![0; index_bound];
+    let mut in_degrees = vec![0; index_bound];
+    for (rank, &u) in ranking.iter().enumerate() {
+        ranks[u] = rank;
+        // The unwrap is safe because the ranking only holds valid node indices.
+        for v in graph.outbound_edges(u).unwrap() {
+            in_degrees[v] += 1;
+        }
+    }
+
+    // The heap stores ranks, so popping the minimum yields the highest priority node.
+    let mut heap: BinaryHeap<Reverse<usize>> = ranking
+        .iter()
+        .filter(|&&u| in_degrees[u] == 0)
+        .map(|&u| Reverse(ranks[u]))
+        .collect();
+
+    let mut sorted_list = Vec::with_capacity(ranking.len());
+    while let Some(Reverse(rank)) = heap.pop() {
+        let u = ranking[rank];
+        sorted_list.push(u);
+        for v in graph.outbound_edges(u).unwrap() {
+            in_degrees[v] -= 1;
+            if in_degrees[v] == 0 {
+                heap.push(Reverse(ranks[v]));
+            }
+        }
+    }
+
+    if sorted_list.len() == ranking.len() {
+        Some(sorted_list)
+    } else {
+        None // Cycle detected
+    }
+}
@@ -15,6 +15,16 @@ pub enum GraphError {
 
     /// The operation could not be completed because the graph contains a cycle.
     GraphContainsCycle,
+
+    /// An operation was attempted on a hyperedge index that does not exist or has been removed.
+    HyperedgeNotFound(usize),
+
+    /// The operation could not be completed because the graph contains a cycle with a
+    /// negative total weight. The index identifies one node on that cycle.
+    GraphContainsNegativeCycle(usize),
+
+    /// The operation requires a designated root node, but the graph has none.
+    RootNotFound,
 }
 
 impl fmt::Display for GraphError {
@@ -40,6 +50,26 @@ impl fmt::Display for GraphError {
             Self::GraphContainsCycle => {
                 write!(f, "Operation failed because the graph contains a cycle.")
             }
+            Self::HyperedgeNotFound(index) => {
+                write!(
+                    f,
+                    "Hyperedge with index {} not found; it may be out of bounds or have been removed.",
+                    index
+                )
+            }
+            Self::GraphContainsNegativeCycle(index) => {
+                write!(
+                    f,
+                    "Operation failed because the graph contains a negative-weight cycle through node {}.",
+                    index
+                )
+            }
+            Self::RootNotFound => {
+                write!(
+                    f,
+                    "Operation failed because the graph has no designated root node."
+                )
+            }
         }
     }
 }
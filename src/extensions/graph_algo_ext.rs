@@ -1,7 +1,8 @@
 #![cfg(feature = "parallel")]
 // This entire module becomes available only  if the parallel feature is enabled.
-use crate::{CsmGraph, GraphTraversal, GraphView};
+use crate::{CsmGraph, GraphError, GraphTraversal, GraphView};
 use rayon::prelude::*;
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 
 /// A trait that provides parallel versions of graph algorithms.
@@ -28,6 +29,41 @@ where
     /// graph is a DAG. Returns `None` if the graph contains a cycle.
     fn topological_sort_par(&self) -> Option<Vec<usize>>;
 
+    /// Computes the topological generations (layers) of the graph in parallel.
+    ///
+    /// This is the parallel counterpart of `DagAlgorithms::topological_generations` and
+    /// returns exactly the same layers.
+    ///
+    /// # Returns
+    /// `Ok(Vec<Vec<usize>>)` with one sorted list of node indices per layer. Returns
+    /// `GraphError::GraphContainsCycle` if the graph contains a cycle.
+    fn topological_generations_par(&self) -> Result<Vec<Vec<usize>>, GraphError>;
+
+    /// Computes the strongly connected components (SCCs) of the graph in parallel.
+    ///
+    /// This is the parallel counterpart of
+    /// `StructuralGraphAlgorithms::strongly_connected_components`. It uses the
+    /// forward-backward (FW-BW) decomposition with trimming, which needs both the
+    /// successors and the predecessors of every node.
+    ///
+    /// # Returns
+    /// A tuple `(components, count)` where `components[n]` is the component id of
+    /// node `n` and ids range over `0..count`. Ids are deterministic and ordered by the
+    /// smallest node index in each component; unlike the sequential version, they do
+    /// not follow a topological order.
+    fn strongly_connected_components_par(&self) -> (Vec<usize>, usize);
+
+    /// Computes the weakly connected components of the graph in parallel.
+    ///
+    /// This is the parallel counterpart of
+    /// `StructuralGraphAlgorithms::weakly_connected_components` and returns exactly
+    /// the same labels: ids are ordered by the smallest node index in each component.
+    ///
+    /// # Returns
+    /// A tuple `(components, count)` where `components[n]` is the component id of
+    /// node `n` and ids range over `0..count`.
+    fn weakly_connected_components_par(&self) -> (Vec<usize>, usize);
+
     // --- Parallel Pathfinding and Reachability Algorithms ---
 
     /// Checks if a path of any length exists from a start to a stop index, using a parallel BFS.
@@ -77,65 +113,189 @@ where
     /// - **Space Complexity:** O(V) for storing in-degrees, the frontier, and the result list.
     ///
     fn topological_sort_par(&self) -> Option<Vec<usize>> {
+        let (generations, num_sorted) = frontiers_par(self);
+
+        // Validate the result.
+        if num_sorted == self.number_nodes() {
+            Some(generations.concat())
+        } else {
+            None // A cycle was detected.
+        }
+    }
+
+    /// Computes the topological generations in parallel using Kahn's algorithm.
+    ///
+    /// This runs the same parallel frontier loop as `topological_sort_par`, but keeps each
+    /// frontier as its own layer instead of flattening them. All nodes in one layer only
+    /// depend on nodes in earlier layers, so they can be processed concurrently.
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** O(V + E), with the wall-clock time reduced by processing each
+    ///   frontier in parallel.
+    /// - **Space Complexity:** O(V) for storing in-degrees and the layers.
+    fn topological_generations_par(&self) -> Result<Vec<Vec<usize>>, GraphError> {
+        let (generations, num_sorted) = frontiers_par(self);
+
+        if num_sorted == self.number_nodes() {
+            Ok(generations)
+        } else {
+            Err(GraphError::GraphContainsCycle)
+        }
+    }
+
+    /// Computes the strongly connected components in parallel using the forward-backward
+    /// (FW-BW) algorithm with trimming.
+    ///
+    /// The graph is split into independent subproblems, each identified by a color
+    /// stored in a `Vec<AtomicUsize>`. For every subproblem:
+    ///
+    /// 1. **Trim:** Nodes without an in-edge or without an out-edge inside the
+    ///    subproblem cannot be on a cycle, so each forms its own SCC. This is repeated
+    ///    in parallel until no more nodes can be trimmed, which removes long chains cheaply.
+    /// 2. **Forward-Backward:** A pivot is picked and its forward reachable set (FW) is
+    ///    found with a parallel BFS over `forward_edges`. A second parallel BFS over
+    ///    `backward_edges` finds the backward reachable set (BW). Nodes are "claimed" with
+    ///    atomic `compare_exchange` operations on their color. FW ∩ BW is the pivot's SCC.
+    /// 3. **Split:** The remaining nodes fall into FW \ SCC, BW \ SCC and the rest. No
+    ///    SCC spans two of these sets, so they become three new independent subproblems.
+    ///
+    /// All subproblems of one round are processed in parallel.
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** O((V + E) log V) expected work for random pivots; trimming
+    ///   makes the common case of large acyclic regions close to O(V + E).
+    /// - **Space Complexity:** O(V) for colors, component ids and the subproblem lists.
+    fn strongly_connected_components_par(&self) -> (Vec<usize>, usize) {
         let num_nodes = self.number_nodes();
         if num_nodes == 0 {
-            return Some(Vec::new());
+            return (Vec::new(), 0);
         }
 
-        // 1. Compute in-degrees. A sequential pass is extremely fast and cache-friendly.
-        let mut in_degrees_val = vec![0; num_nodes];
-        for i in 0..num_nodes {
-            // The unwrap is safe because we are iterating within the bounds of existing nodes.
-            for neighbor in self.outbound_edges(i).unwrap() {
-                in_degrees_val[neighbor] += 1;
-            }
+        let state = SccState {
+            colors: (0..num_nodes).map(|_| AtomicUsize::new(0)).collect(),
+            components: (0..num_nodes)
+                .map(|_| AtomicUsize::new(UNASSIGNED))
+                .collect(),
+            next_color: AtomicUsize::new(1),
+            next_component: AtomicUsize::new(0),
+        };
+
+        // Start with a single subproblem (color 0) that contains all nodes.
+        let mut subproblems = vec![(0, (0..num_nodes).collect::<Vec<usize>>())];
+        while !subproblems.is_empty() {
+            subproblems = subproblems
+                .into_par_iter()
+                .flat_map_iter(|(color, nodes)| fw_bw_step(self, &state, color, nodes))
+                .collect();
         }
 
-        // Convert to atomic integers for safe concurrent modification.
-        let in_degrees: Vec<AtomicUsize> =
-            in_degrees_val.into_iter().map(AtomicUsize::new).collect();
-
-        // 2. Find the initial frontier of nodes with zero in-degrees in parallel.
-        let mut frontier: Vec<usize> = (0..num_nodes)
-            .into_par_iter()
-            .filter(|&i| in_degrees[i].load(Ordering::Relaxed) == 0)
+        // Relabel the raw component ids deterministically by their smallest member.
+        let count = state.next_component.load(Ordering::Relaxed);
+        let mut relabel = vec![UNASSIGNED; count];
+        let mut next_label = 0;
+        let components = state
+            .components
+            .iter()
+            .map(|raw| {
+                let raw = raw.load(Ordering::Relaxed);
+                if relabel[raw] == UNASSIGNED {
+                    relabel[raw] = next_label;
+                    next_label += 1;
+                }
+                relabel[raw]
+            })
             .collect();
 
-        let mut sorted_list = Vec::with_capacity(num_nodes);
-
-        // 3. Process frontiers in parallel until no nodes are left.
-        while !frontier.is_empty() {
-            frontier.sort_unstable(); // For deterministic output
-            sorted_list.extend(&frontier);
+        (components, count)
+    }
 
-            let next_frontier: Vec<usize> = frontier
-                .par_iter()
-                .map(|&u| {
-                    // Each parallel task produces a Vec of its "ready" neighbors
-                    self.outbound_edges(u)
-                        .unwrap()
-                        .filter_map(|v| {
-                            // Atomically decrement the in-degree. `fetch_sub` returns the *previous* value.
-                            if in_degrees[v].fetch_sub(1, Ordering::Relaxed) == 1 {
-                                Some(v)
-                            } else {
-                                None
-                            }
-                        })
-                        .collect::<Vec<_>>()
-                })
-                .flatten() // Use Rayon's parallel flatten combinator
-                .collect();
+    /// Computes the weakly connected components in parallel using a lock-free
+    /// union-find in the style of Afforest (an extension of Shiloach-Vishkin).
+    ///
+    /// Every node starts in its own tree of a shared `Vec<AtomicUsize>` parent array.
+    /// Edges are "linked" by hooking the larger of two roots under the smaller one with
+    /// a `compare_exchange`, so the root of each tree is always its smallest node.
+    ///
+    /// 1. **Neighbor Rounds:** Only the first few out-edges of every node are linked,
+    ///    followed by a parallel pointer-jumping compression. On most graphs this is
+    ///    already enough to form the bulk of the largest component.
+    /// 2. **Sampling:** A sample of nodes is used to guess the largest component.
+    /// 3. **Finish:** All remaining edges are linked, skipping every node that is
+    ///    already in the largest component. Since edges are directed, the skipped
+    ///    nodes' edges are covered by also linking the in-edges of the other nodes.
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** O(V + E) work in practice, usually much less as most edges
+    ///   of the largest component are skipped.
+    /// - **Space Complexity:** O(V) for the parent array and the labels.
+    fn weakly_connected_components_par(&self) -> (Vec<usize>, usize) {
+        // Number of out-edges per node that are linked before sampling.
+        const NEIGHBOR_ROUNDS: usize = 2;
+        // Number of nodes sampled to find the largest intermediate component.
+        const NUM_SAMPLES: usize = 1024;
 
-            frontier = next_frontier;
+        let num_nodes = self.number_nodes();
+        let parent: Vec<AtomicUsize> = (0..num_nodes).map(AtomicUsize::new).collect();
+
+        // 1. Link the first few out-edges of every node.
+        for round in 0..NEIGHBOR_ROUNDS {
+            (0..num_nodes).into_par_iter().for_each(|u| {
+                // The unwrap is safe because we are dealing with valid node indices.
+                if let Some(v) = self.outbound_edges(u).unwrap().nth(round) {
+                    afforest_link(&parent, u, v);
+                }
+            });
+            afforest_compress(&parent);
         }
 
-        // 4. Validate the result.
-        if sorted_list.len() == num_nodes {
-            Some(sorted_list)
-        } else {
-            None // A cycle was detected.
+        // 2. Guess the largest component from evenly spaced samples. Sampling with a fixed
+        //    stride keeps the result deterministic.
+        let stride = num_nodes.div_ceil(NUM_SAMPLES).max(1);
+        let mut sample_counts: HashMap<usize, usize> = HashMap::new();
+        for u in (0..num_nodes).step_by(stride) {
+            *sample_counts
+                .entry(parent[u].load(Ordering::Relaxed))
+                .or_default() += 1;
         }
+        let largest = sample_counts
+            .into_iter()
+            .max_by_key(|&(root, count)| (count, std::cmp::Reverse(root)))
+            .map(|(root, _)| root);
+
+        // 3. Link all remaining edges of the nodes outside the largest component.
+        (0..num_nodes).into_par_iter().for_each(|u| {
+            if Some(parent[u].load(Ordering::Relaxed)) == largest {
+                return;
+            }
+            for v in self.outbound_edges(u).unwrap().skip(NEIGHBOR_ROUNDS) {
+                afforest_link(&parent, u, v);
+            }
+            for v in self.inbound_edges(u).unwrap() {
+                afforest_link(&parent, u, v);
+            }
+        });
+        afforest_compress(&parent);
+
+        // Every root is the smallest node of its tree, so it is labeled before its members.
+        let mut labels = vec![UNASSIGNED; num_nodes];
+        let mut count = 0;
+        let components = parent
+            .iter()
+            .enumerate()
+            .map(|(u, p)| {
+                let root = p.load(Ordering::Relaxed);
+                if root == u {
+                    labels[u] = count;
+                    count += 1;
+                }
+                labels[root]
+            })
+            .collect();
+
+        (components, count)
     }
 
     /// Checks if a path exists from a start to a stop index using a parallel BFS.
@@ -204,14 +364,10 @@ where
                     // The unwrap is safe because we are dealing with valid node indices.
                     self.outbound_edges(u)
                         .unwrap()
-                        .filter_map(|v| {
+                        .filter(|&v| {
                             // Atomically "claim" the node. `swap` returns the previous value.
                             // If it was `false`, this thread is the first to visit it.
-                            if !visited[v].swap(true, Ordering::Relaxed) {
-                                Some(v)
-                            } else {
-                                None
-                            }
+                            !visited[v].swap(true, Ordering::Relaxed)
                         })
                         .collect::<Vec<_>>()
                 })
@@ -283,26 +439,22 @@ where
 
                     self.outbound_edges(u)
                         .unwrap()
-                        .filter_map(|v| {
+                        .filter(|&v| {
                             // Atomically "claim" the node by setting its predecessor.
                             // `compare_exchange` only succeeds if the current value is `UNVISITED`.
-                            if predecessors[v]
+                            let claimed = predecessors[v]
                                 .compare_exchange(
                                     UNVISITED,
                                     u,
                                     Ordering::Relaxed,
                                     Ordering::Relaxed,
                                 )
-                                .is_ok()
-                            {
-                                // If we found the target, set the flag to stop other threads.
-                                if v == stop_index {
-                                    target_found.store(true, Ordering::Relaxed);
-                                }
-                                Some(v)
-                            } else {
-                                None
+                                .is_ok();
+                            // If we found the target, set the flag to stop other threads.
+                            if claimed && v == stop_index {
+                                target_found.store(true, Ordering::Relaxed);
                             }
+                            claimed
                         })
                         .collect::<Vec<_>>()
                 })
@@ -341,3 +493,242 @@ where
         }
     }
 }
+
+// Marks a node whose SCC has been found, both as color and as unset component id.
+const UNASSIGNED: usize = usize::MAX;
+
+/// Private shared state of the parallel FW-BW SCC decomposition.
+struct SccState {
+    // The subproblem each node currently belongs to, or `UNASSIGNED` once its SCC is known.
+    colors: Vec<AtomicUsize>,
+    // The raw (not yet normalized) component id of each node.
+    components: Vec<AtomicUsize>,
+    // Allocators for fresh subproblem colors and component ids.
+    next_color: AtomicUsize,
+    next_component: AtomicUsize,
+}
+
+impl SccState {
+    /// Assigns all `nodes` to one freshly allocated component.
+    fn assign_component(&self, nodes: &[usize]) {
+        let component = self.next_component.fetch_add(1, Ordering::Relaxed);
+        for &v in nodes {
+            self.colors[v].store(UNASSIGNED, Ordering::Relaxed);
+            self.components[v].store(component, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Processes one FW-BW subproblem and returns the new subproblems it splits into.
+fn fw_bw_step<N, W>(
+    graph: &CsmGraph<N, W>,
+    state: &SccState,
+    color: usize,
+    mut nodes: Vec<usize>,
+) -> Vec<(usize, Vec<usize>)>
+where
+    N: Send + Sync,
+    W: Send + Sync + Default,
+{
+    let has_color = |v: usize, c: usize| state.colors[v].load(Ordering::Relaxed) == c;
+
+    // 1. Trim nodes that have no in-edge or no out-edge within the subproblem.
+    loop {
+        let trimmed: Vec<usize> = nodes
+            .par_iter()
+            .copied()
+            .filter(|&v| {
+                // The unwraps are safe because we are dealing with valid node indices.
+                let no_out = !graph
+                    .outbound_edges(v)
+                    .unwrap()
+                    .any(|w| has_color(w, color));
+                no_out || !graph.inbound_edges(v).unwrap().any(|w| has_color(w, color))
+            })
+            .collect();
+        if trimmed.is_empty() {
+            break;
+        }
+        for &v in &trimmed {
+            state.assign_component(&[v]);
+        }
+        nodes.retain(|&v| has_color(v, color));
+    }
+
+    let Some(&pivot) = nodes.first() else {
+        return Vec::new();
+    };
+
+    let fw_color = state.next_color.fetch_add(3, Ordering::Relaxed);
+    let bw_color = fw_color + 1;
+    let scc_color = fw_color + 2;
+
+    // 2a. Forward BFS: claim every node of the subproblem reachable from the pivot.
+    state.colors[pivot].store(fw_color, Ordering::Relaxed);
+    let mut frontier = vec![pivot];
+    while !frontier.is_empty() {
+        frontier = frontier
+            .par_iter()
+            .map(|&u| {
+                graph
+                    .outbound_edges(u)
+                    .unwrap()
+                    .filter(|&v| {
+                        state.colors[v]
+                            .compare_exchange(color, fw_color, Ordering::Relaxed, Ordering::Relaxed)
+                            .is_ok()
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .flatten()
+            .collect();
+    }
+
+    // 2b. Backward BFS: nodes already in FW move to the SCC, all others move to BW.
+    state.colors[pivot].store(scc_color, Ordering::Relaxed);
+    let mut frontier = vec![pivot];
+    while !frontier.is_empty() {
+        frontier = frontier
+            .par_iter()
+            .map(|&u| {
+                graph
+                    .inbound_edges(u)
+                    .unwrap()
+                    .filter(|&v| {
+                        let claim = |from, to| {
+                            state.colors[v]
+                                .compare_exchange(from, to, Ordering::Relaxed, Ordering::Relaxed)
+                                .is_ok()
+                        };
+                        claim(fw_color, scc_color) || claim(color, bw_color)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .flatten()
+            .collect();
+    }
+
+    // 3. Record the pivot's SCC and split the rest into three independent subproblems.
+    let scc: Vec<usize> = nodes
+        .par_iter()
+        .copied()
+        .filter(|&v| has_color(v, scc_color))
+        .collect();
+    state.assign_component(&scc);
+
+    [fw_color, bw_color, color]
+        .into_iter()
+        .map(|c| {
+            let subset: Vec<usize> = nodes
+                .par_iter()
+                .copied()
+                .filter(|&v| has_color(v, c))
+                .collect();
+            (c, subset)
+        })
+        .filter(|(_, subset)| !subset.is_empty())
+        .collect()
+}
+
+/// Links the trees of `u` and `v` by hooking the larger root under the smaller one.
+///
+/// The hook only succeeds if the larger node is still a root, so concurrent links
+/// never lose a merge; on failure the roots are re-read and the link is retried.
+fn afforest_link(parent: &[AtomicUsize], u: usize, v: usize) {
+    let mut p1 = parent[u].load(Ordering::Relaxed);
+    let mut p2 = parent[v].load(Ordering::Relaxed);
+    while p1 != p2 {
+        let (high, low) = if p1 > p2 { (p1, p2) } else { (p2, p1) };
+        let p_high = parent[high].load(Ordering::Relaxed);
+        // Already linked, or successfully hooked `high` under `low`.
+        if p_high == low
+            || (p_high == high
+                && parent[high]
+                    .compare_exchange(high, low, Ordering::Relaxed, Ordering::Relaxed)
+                    .is_ok())
+        {
+            break;
+        }
+        p1 = parent[parent[high].load(Ordering::Relaxed)].load(Ordering::Relaxed);
+        p2 = parent[low].load(Ordering::Relaxed);
+    }
+}
+
+/// Compresses every tree in parallel so that each node points directly to its root.
+fn afforest_compress(parent: &[AtomicUsize]) {
+    (0..parent.len()).into_par_iter().for_each(|u| {
+        loop {
+            let p = parent[u].load(Ordering::Relaxed);
+            let grandparent = parent[p].load(Ordering::Relaxed);
+            if p == grandparent {
+                break;
+            }
+            parent[u].store(grandparent, Ordering::Relaxed);
+        }
+    });
+}
+
+/// Runs the parallel Kahn frontier loop and returns the sorted frontiers (generations)
+/// together with the number of nodes they contain.
+///
+/// The algorithm first performs a fast sequential pass to calculate the in-degrees of all
+/// nodes. It then identifies an initial "frontier" of nodes with zero in-degrees. In each
+/// subsequent step, it processes the current frontier in parallel, atomically decrementing
+/// the in-degrees of neighbor nodes and collecting those that reach an in-degree of zero
+/// into the next frontier. If the graph contains a cycle, the nodes on or behind it are
+/// never reached, so fewer than `number_nodes()` nodes are returned.
+fn frontiers_par<N, W>(graph: &CsmGraph<N, W>) -> (Vec<Vec<usize>>, usize)
+where
+    N: Send + Sync,
+    W: Send + Sync + Default,
+{
+    let num_nodes = graph.number_nodes();
+
+    // 1. Compute in-degrees. A sequential pass is extremely fast and cache-friendly.
+    let mut in_degrees_val = vec![0; num_nodes];
+    for i in 0..num_nodes {
+        // The unwrap is safe because we are iterating within the bounds of existing nodes.
+        for neighbor in graph.outbound_edges(i).unwrap() {
+            in_degrees_val[neighbor] += 1;
+        }
+    }
+
+    // Convert to atomic integers for safe concurrent modification.
+    let in_degrees: Vec<AtomicUsize> = in_degrees_val.into_iter().map(AtomicUsize::new).collect();
+
+    // 2. Find the initial frontier of nodes with zero in-degrees in parallel.
+    let mut frontier: Vec<usize> = (0..num_nodes)
+        .into_par_iter()
+        .filter(|&i| in_degrees[i].load(Ordering::Relaxed) == 0)
+        .collect();
+
+    let mut generations = Vec::new();
+    let mut num_sorted = 0;
+
+    // 3. Process frontiers in parallel until no nodes are left.
+    while !frontier.is_empty() {
+        frontier.sort_unstable(); // For deterministic output
+
+        let next_frontier: Vec<usize> = frontier
+            .par_iter()
+            .map(|&u| {
+                // Each parallel task produces a Vec of its "ready" neighbors
+                graph
+                    .outbound_edges(u)
+                    .unwrap()
+                    .filter(|&v| {
+                        // Atomically decrement the in-degree. `fetch_sub` returns the *previous* value.
+                        in_degrees[v].fetch_sub(1, Ordering::Relaxed) == 1
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .flatten() // Use Rayon's parallel flatten combinator
+            .collect();
+
+        num_sorted += frontier.len();
+        generations.push(frontier);
+        frontier = next_frontier;
+    }
+
+    (generations, num_sorted)
+}
@@ -1,3 +1,4 @@
+pub mod algo;
 mod errors;
 mod extensions;
 mod traits;
@@ -10,12 +11,36 @@ pub use errors::graph_error::GraphError;
 #[cfg(feature = "parallel")]
 pub use extensions::graph_algo_ext::ParallelGraphAlgorithmsExt;
 // traits
+pub use traits::directed_hypergraph_freeze::DirectedHypergraphFreezable;
+pub use traits::directed_hypergraph_mut::DirectedHypergraphMut;
+pub use traits::directed_hypergraph_traversal::DirectedHypergraphTraversal;
+pub use traits::directed_hypergraph_unfreeze::DirectedHypergraphUnfreezable;
 pub use traits::graph_algo::GraphAlgorithms;
+pub use traits::graph_algo_causal::CausalGraphAlgorithms;
+pub use traits::graph_algo_dag::DagAlgorithms;
+pub use traits::graph_algo_structural::StructuralGraphAlgorithms;
+pub use traits::graph_algo_weighted::{ShortestPathTree, WeightedGraphAlgorithms};
 pub use traits::graph_freeze::Freezable;
 pub use traits::graph_mut::GraphMut;
 pub use traits::graph_traversal::GraphTraversal;
 pub use traits::graph_unfreeze::Unfreezable;
 pub use traits::graph_view::GraphView;
+pub use traits::graph_weight::Weight;
+pub use traits::hypergraph_algo::HypergraphAlgorithms;
+pub use traits::hypergraph_freeze::HypergraphFreezable;
+pub use traits::hypergraph_mut::HypergraphMut;
+pub use traits::hypergraph_traversal::HypergraphTraversal;
+pub use traits::hypergraph_unfreeze::HypergraphUnfreezable;
+pub use traits::hypergraph_view::HypergraphView;
 // types
+pub use types::dominator_tree::DominatorTree;
 pub use types::graph_csm::CsmGraph;
 pub use types::graph_dynamic::DynamicGraph;
+pub use types::hypergraph_csm::Hypergraph;
+pub use types::hypergraph_directed_csm::DirectedHypergraph;
+pub use types::hypergraph_directed_dynamic::DynamicDirectedHypergraph;
+pub use types::hypergraph_dynamic::DynamicHypergraph;
+pub use types::reachability_index::ReachabilityIndex;
+pub use types::transitive_closure::TransitiveClosure;
+pub use types::traversal::{Bfs, Dfs, DfsEvent, DfsEvents, depth_first_search};
+pub use types::traversal_workspace::TraversalWorkspace;
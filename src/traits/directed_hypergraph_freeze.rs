@@ -0,0 +1,5 @@
+use crate::DirectedHypergraph;
+
+pub trait DirectedHypergraphFreezable<N, E> {
+    fn freeze(self) -> DirectedHypergraph<N, E>;
+}
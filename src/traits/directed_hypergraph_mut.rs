@@ -0,0 +1,41 @@
+use crate::{GraphError, HypergraphView};
+
+pub trait DirectedHypergraphMut<N, E>: HypergraphView<N, E> {
+    // Node Mutation
+    fn add_node(&mut self, node: N) -> usize;
+    fn update_node(&mut self, index: usize, node: N) -> Result<(), GraphError>;
+
+    /// Removes a node from the hypergraph, effectively "tombstoning" it.
+    ///
+    /// The node is logically removed from the tail and head sets of every
+    /// hyperedge, but the sets are only compacted when the hypergraph is `freeze`n.
+    ///
+    /// # Errors
+    /// Returns `GraphError::NodeNotFound` if the index is out of bounds or if the
+    /// node at that index has already been removed.
+    fn remove_node(&mut self, index: usize) -> Result<(), GraphError>;
+
+    // Hyperedge Mutation
+
+    /// Adds a directed hyperedge from the `tail` node set to the `head` node set
+    /// and returns its stable index.
+    ///
+    /// For example, "A and B jointly cause C and D" is expressed as
+    /// `add_hyperedge(&[a, b], &[c, d], payload)`. Duplicate indices within a set
+    /// are collapsed; a node may appear in both sets.
+    ///
+    /// # Errors
+    /// Returns `GraphError::NodeNotFound` for the first index in `tail` or `head`
+    /// that does not exist or has been removed.
+    fn add_hyperedge(
+        &mut self,
+        tail: &[usize],
+        head: &[usize],
+        payload: E,
+    ) -> Result<usize, GraphError>;
+    fn update_hyperedge(&mut self, index: usize, payload: E) -> Result<(), GraphError>;
+    fn remove_hyperedge(&mut self, index: usize) -> Result<(), GraphError>;
+
+    // Graph-wide Mutation
+    fn clear(&mut self);
+}
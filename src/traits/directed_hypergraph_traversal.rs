@@ -0,0 +1,24 @@
+use crate::{GraphError, HypergraphView};
+
+pub trait DirectedHypergraphTraversal<N, E>: HypergraphView<N, E> {
+    // --- Hyperedge Inspection ---
+
+    /// Returns an iterator over the tail (source) nodes of hyperedge `e`, in ascending index order.
+    fn hyperedge_tail(&self, e: usize) -> Result<impl Iterator<Item = usize> + '_, GraphError>;
+
+    /// Returns an iterator over the head (target) nodes of hyperedge `e`, in ascending index order.
+    fn hyperedge_head(&self, e: usize) -> Result<impl Iterator<Item = usize> + '_, GraphError>;
+
+    // --- Traversal ---
+
+    /// Returns an iterator over the hyperedges leaving node `a`, i.e. those with `a` in their tail.
+    ///
+    /// This is the hyperedge-aware counterpart of `GraphTraversal::outbound_edges`.
+    fn outbound_hyperedges(&self, a: usize)
+    -> Result<impl Iterator<Item = usize> + '_, GraphError>;
+
+    /// Returns an iterator over the hyperedges entering node `a`, i.e. those with `a` in their head.
+    ///
+    /// This is the hyperedge-aware counterpart of `GraphTraversal::inbound_edges`.
+    fn inbound_hyperedges(&self, a: usize) -> Result<impl Iterator<Item = usize> + '_, GraphError>;
+}
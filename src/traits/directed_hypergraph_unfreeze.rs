@@ -0,0 +1,5 @@
+use crate::DynamicDirectedHypergraph;
+
+pub trait DirectedHypergraphUnfreezable<N, E> {
+    fn unfreeze(self) -> DynamicDirectedHypergraph<N, E>;
+}
@@ -1,4 +1,5 @@
-use crate::GraphView;
+use crate::{GraphTraversal, GraphView, algo};
+use std::cmp::Ordering;
 
 /// Defines a suite of high-performance, read-only analytical algorithms.
 ///
@@ -27,6 +28,36 @@ pub trait GraphAlgorithms<N, W>: GraphView<N, W> {
     /// Returns `None` if the graph contains a cycle.
     fn topological_sort(&self) -> Option<Vec<usize>>;
 
+    /// Computes a topological sort that orders ready nodes by their payload.
+    ///
+    /// Whenever several nodes could come next, the one with the smallest payload
+    /// according to `compare` is picked, with ties broken by node index. Unlike
+    /// `topological_sort`, the result does not depend on the order of insertion.
+    /// Returns `None` if the graph contains a cycle.
+    ///
+    /// The default implementation calls `algo::topological_sort_by` and is available
+    /// for every implementor that also implements `GraphTraversal`.
+    fn topological_sort_by<F>(&self, compare: F) -> Option<Vec<usize>>
+    where
+        Self: GraphTraversal<N, W> + Sized,
+        F: FnMut(&N, &N) -> Ordering,
+    {
+        algo::topological_sort_by(self, compare)
+    }
+
+    /// Computes a topological sort that orders ready nodes by a key of their payload.
+    ///
+    /// The key is computed once per node. See `topological_sort_by` for details.
+    /// Returns `None` if the graph contains a cycle.
+    fn topological_sort_by_key<K, F>(&self, f: F) -> Option<Vec<usize>>
+    where
+        Self: GraphTraversal<N, W> + Sized,
+        K: Ord,
+        F: FnMut(&N) -> K,
+    {
+        algo::topological_sort_by_key(self, f)
+    }
+
     // --- Pathfinding and Reachability Algorithms ---
 
     /// Checks if a path of any length exists from a start to a stop index.
@@ -0,0 +1,156 @@
+use crate::{GraphError, GraphView};
+
+/// Defines the graph queries needed to reason about causal Directed Acyclic Graphs (DAGs).
+///
+/// In a causal DAG, an edge `a -> b` states that `a` is a direct cause of `b`. The
+/// methods answer questions about the causes (ancestors) and effects (descendants)
+/// of a node, and about which nodes are conditionally independent of each other.
+///
+/// This trait is intended for implementation on static, optimized graph structures
+/// like `next_graph::CsmGraph`.
+pub trait CausalGraphAlgorithms<N, W>: GraphView<N, W> {
+    // --- Ancestry ---
+
+    /// Returns all ancestors of a node, i.e., every node that has a path to it.
+    ///
+    /// The node itself is not included, even if it lies on a cycle.
+    ///
+    /// # Returns
+    /// The ancestor indices, sorted in ascending order.
+    ///
+    /// # Errors
+    /// Returns `GraphError::NodeNotFound` if the node does not exist.
+    fn ancestors(&self, node: usize) -> Result<Vec<usize>, GraphError>;
+
+    /// Returns all descendants of a node, i.e., every node reachable from it.
+    ///
+    /// The node itself is not included, even if it lies on a cycle.
+    ///
+    /// # Returns
+    /// The descendant indices, sorted in ascending order.
+    ///
+    /// # Errors
+    /// Returns `GraphError::NodeNotFound` if the node does not exist.
+    fn descendants(&self, node: usize) -> Result<Vec<usize>, GraphError>;
+
+    /// Finds the lowest common ancestors of two nodes.
+    ///
+    /// A common ancestor is a node that has a path to both `a` and `b`, where every node
+    /// counts as its own ancestor. It is "lowest" if none of its descendants is also a
+    /// common ancestor. Unlike in a tree, a DAG may have several lowest common ancestors.
+    ///
+    /// # Returns
+    /// The lowest common ancestors, sorted in ascending order. The result is empty if the
+    /// two nodes share no ancestor, and `[a]` if `a` is an ancestor of `b`.
+    ///
+    /// # Errors
+    /// Returns `GraphError::NodeNotFound` if either node does not exist, and
+    /// `GraphError::GraphContainsCycle` if the graph is not a DAG.
+    fn lowest_common_ancestors(&self, a: usize, b: usize) -> Result<Vec<usize>, GraphError>;
+
+    // --- Conditional Independence ---
+
+    /// Checks whether two sets of nodes are d-separated given a set of observed nodes.
+    ///
+    /// `xs` and `ys` are d-separated by `given` if every path between them is blocked:
+    /// either the path passes through an observed chain or fork node, or it passes
+    /// through a collider (`a -> c <- b`) that is neither observed nor has an observed
+    /// descendant. In a causal DAG, d-separation implies that `xs` and `ys` are
+    /// conditionally independent given `given`.
+    ///
+    /// An observed node is separated from everything, and a node that appears in both
+    /// `xs` and `ys` without being observed is never separated.
+    ///
+    /// # Errors
+    /// Returns `GraphError::NodeNotFound` if any node does not exist, and
+    /// `GraphError::GraphContainsCycle` if the graph is not a DAG.
+    fn is_d_separated(
+        &self,
+        xs: &[usize],
+        ys: &[usize],
+        given: &[usize],
+    ) -> Result<bool, GraphError>;
+
+    /// Returns the Markov blanket of a node: its parents, its children, and the other
+    /// parents of its children.
+    ///
+    /// Given its Markov blanket, a node is conditionally independent of all other nodes
+    /// in a causal DAG.
+    ///
+    /// # Returns
+    /// The indices of the blanket, sorted in ascending order and without the node itself.
+    ///
+    /// # Errors
+    /// Returns `GraphError::NodeNotFound` if the node does not exist.
+    fn markov_blanket(&self, node: usize) -> Result<Vec<usize>, GraphError>;
+
+    // --- Adjustment Sets ---
+
+    /// Finds a minimal set of nodes that satisfies the backdoor criterion for the effect
+    /// of `x` on `y`.
+    ///
+    /// A set `Z` satisfies the backdoor criterion if no node in `Z` is a descendant of
+    /// `x`, and `Z` blocks every path between `x` and `y` that starts with an edge into
+    /// `x`. Adjusting for such a set identifies the causal effect of `x` on `y`.
+    ///
+    /// # Returns
+    /// `Some(set)` with a minimal adjustment set (no node can be removed), sorted in
+    /// ascending order. The set is empty if no adjustment is needed. Returns `None` if
+    /// no valid set exists, i.e., if `x == y` or `y` is a parent of `x`.
+    ///
+    /// # Errors
+    /// Returns `GraphError::NodeNotFound` if either node does not exist, and
+    /// `GraphError::GraphContainsCycle` if the graph is not a DAG.
+    fn backdoor_adjustment_set(&self, x: usize, y: usize)
+    -> Result<Option<Vec<usize>>, GraphError>;
+
+    /// Enumerates all minimal sets of nodes that satisfy the backdoor criterion for the
+    /// effect of `x` on `y`.
+    ///
+    /// Every valid adjustment set contains at least one of the returned sets. The
+    /// enumeration tests subsets of the C ancestors of `x` and `y` that are not
+    /// descendants of `x`, i.e. up to 2^C subsets. Both optional caps bound this work;
+    /// prefer `backdoor_adjustment_set` if any one set is enough.
+    ///
+    /// # Arguments
+    /// * `max_size`: If set, only subsets with at most this many nodes are tested, which
+    ///   limits the work to O(C^max_size) subsets.
+    /// * `max_sets`: If set, the enumeration stops after this many sets are found.
+    ///
+    /// # Returns
+    /// The minimal sets ordered by size, and lexicographically within one size. Each set
+    /// is sorted in ascending order. The result is empty if no valid set exists within
+    /// the caps.
+    ///
+    /// # Errors
+    /// Returns `GraphError::NodeNotFound` if either node does not exist, and
+    /// `GraphError::GraphContainsCycle` if the graph is not a DAG.
+    fn backdoor_adjustment_sets(
+        &self,
+        x: usize,
+        y: usize,
+        max_size: Option<usize>,
+        max_sets: Option<usize>,
+    ) -> Result<Vec<Vec<usize>>, GraphError>;
+
+    /// Checks whether a set of mediators satisfies the frontdoor criterion for the effect
+    /// of `x` on `y`.
+    ///
+    /// The frontdoor criterion identifies the effect even if `x` and `y` share an
+    /// unobserved confounder. It holds if the mediators intercept every directed path
+    /// from `x` to `y`, there is no unblocked backdoor path from `x` to any mediator,
+    /// and `x` blocks every backdoor path from the mediators to `y`.
+    ///
+    /// # Returns
+    /// `true` if the criterion holds. A set that contains `x` or `y` never qualifies.
+    ///
+    /// # Errors
+    /// Returns `GraphError::NodeNotFound` if any node does not exist, and
+    /// `GraphError::GraphContainsCycle` if the graph is not a DAG.
+    fn is_frontdoor_adjustment_set(
+        &self,
+        x: usize,
+        y: usize,
+        mediators: &[usize],
+    ) -> Result<bool, GraphError>;
+}
@@ -0,0 +1,115 @@
+use crate::{CsmGraph, GraphError, GraphView, TransitiveClosure, Weight};
+
+/// Defines algorithms that are only meaningful on Directed Acyclic Graphs (DAGs).
+///
+/// Every method first validates that the graph is acyclic and returns
+/// `GraphError::GraphContainsCycle` otherwise.
+///
+/// This trait is intended for implementation on static, optimized graph structures
+/// like `next_graph::CsmGraph`.
+pub trait DagAlgorithms<N, W>: GraphView<N, W> {
+    // --- Ordering ---
+
+    /// Computes the topological generations (layers) of the DAG.
+    ///
+    /// The first generation holds all nodes without predecessors, and every later
+    /// generation holds the nodes whose predecessors all lie in earlier generations.
+    /// Nodes within one generation do not depend on each other, so they can be
+    /// processed in parallel.
+    ///
+    /// # Returns
+    /// One list of node indices per generation, each sorted in ascending order.
+    ///
+    /// # Errors
+    /// Returns `GraphError::GraphContainsCycle` if the graph is not a DAG.
+    fn topological_generations(&self) -> Result<Vec<Vec<usize>>, GraphError>;
+
+    // --- Transitivity ---
+
+    /// Computes the transitive closure of the DAG as a compact bitset index.
+    ///
+    /// The returned `TransitiveClosure` answers `is_reachable` queries in O(1), at
+    /// the cost of V² / 8 bytes of memory.
+    ///
+    /// # Errors
+    /// Returns `GraphError::GraphContainsCycle` if the graph is not a DAG.
+    fn transitive_closure(&self) -> Result<TransitiveClosure, GraphError>;
+
+    /// Computes the transitive reduction of the DAG.
+    ///
+    /// The transitive reduction is the unique graph with the fewest edges that has the
+    /// same reachability as the original. An edge `u -> v` is removed if `v` can also
+    /// be reached from `u` through another path, and parallel edges are merged into one.
+    ///
+    /// # Returns
+    /// A new `CsmGraph` with the same nodes, root and node indices. Each kept edge
+    /// retains its original weight.
+    ///
+    /// # Errors
+    /// Returns `GraphError::GraphContainsCycle` if the graph is not a DAG.
+    fn transitive_reduction(&self) -> Result<CsmGraph<N, W>, GraphError>
+    where
+        N: Clone,
+        W: Clone + Default;
+
+    // --- Longest Paths ---
+
+    /// Finds the longest path in the DAG, measured by the sum of its edge weights.
+    ///
+    /// A path may start and end at any node, so edges with negative weights are only
+    /// part of the result if they lead to a longer path overall.
+    ///
+    /// # Returns
+    /// A tuple `(path, length)` with the node indices of the longest path and its total
+    /// weight. If several paths share the maximum length, the one ending first in
+    /// topological order is returned. A graph without edges yields a single node with
+    /// length zero, and an empty graph yields an empty path.
+    ///
+    /// # Errors
+    /// Returns `GraphError::GraphContainsCycle` if the graph is not a DAG.
+    fn longest_path(&self) -> Result<(Vec<usize>, W), GraphError>
+    where
+        W: Weight;
+
+    /// Finds the critical path of the DAG, where every edge is a task with a duration.
+    ///
+    /// This is a longest path search over the edge weights, mapped to durations by
+    /// `weights`. It allows scheduling graphs whose edge weights are not numbers
+    /// themselves, e.g. a struct that carries the duration of a pipeline stage.
+    ///
+    /// # Arguments
+    /// * `weights`: A function returning the duration of an edge, given its weight.
+    ///
+    /// # Returns
+    /// A tuple `(path, length)` with the node indices of the critical path and the sum of
+    /// its edge durations. A graph without edges yields a single node with length zero,
+    /// and an empty graph yields an empty path.
+    ///
+    /// # Errors
+    /// Returns `GraphError::GraphContainsCycle` if the graph is not a DAG.
+    fn critical_path<C>(&self, weights: impl Fn(&W) -> C) -> Result<(Vec<usize>, C), GraphError>
+    where
+        C: Weight;
+
+    /// Finds the critical path of the DAG, where every node is a task with a duration.
+    ///
+    /// Edges are treated as precedence constraints: a task can only start once all of
+    /// its predecessors are done. The critical path is the chain of tasks with the
+    /// largest total duration, which bounds the completion time of the whole schedule.
+    ///
+    /// # Arguments
+    /// * `durations`: A function returning the duration of a task, given its node index.
+    ///
+    /// # Returns
+    /// A tuple `(path, length)` with the node indices of the critical path and the sum of
+    /// their durations. An empty graph yields an empty path with length zero.
+    ///
+    /// # Errors
+    /// Returns `GraphError::GraphContainsCycle` if the graph is not a DAG.
+    fn critical_path_by_durations<C>(
+        &self,
+        durations: impl Fn(usize) -> C,
+    ) -> Result<(Vec<usize>, C), GraphError>
+    where
+        C: Weight;
+}
@@ -0,0 +1,111 @@
+use crate::{CsmGraph, DominatorTree, GraphError, GraphView};
+
+/// Defines algorithms that decompose a graph into its structural building blocks.
+///
+/// This trait is intended for implementation on static, optimized graph structures
+/// like `next_graph::CsmGraph`.
+pub trait StructuralGraphAlgorithms<N, W>: GraphView<N, W> {
+    // --- Connectivity ---
+
+    /// Computes the strongly connected components (SCCs) of the graph.
+    ///
+    /// Two nodes are in the same SCC if each is reachable from the other. Every
+    /// directed cycle lies entirely within one SCC.
+    ///
+    /// # Returns
+    /// A tuple `(components, count)` where `components[n]` is the component id of
+    /// node `n` and ids range over `0..count`. Ids follow a topological order of the
+    /// components: an edge between two different components always points from a
+    /// lower id to a higher id.
+    fn strongly_connected_components(&self) -> (Vec<usize>, usize);
+
+    /// Computes the weakly connected components of the graph.
+    ///
+    /// Two nodes are in the same weakly connected component if they are connected
+    /// when edge directions are ignored, i.e. they belong to the same "island".
+    ///
+    /// # Returns
+    /// A tuple `(components, count)` where `components[n]` is the component id of
+    /// node `n` and ids range over `0..count`. Ids are ordered by the smallest node
+    /// index in each component, so node `0` is always in component `0`.
+    fn weakly_connected_components(&self) -> (Vec<usize>, usize);
+
+    /// Finds the articulation points (cut vertices) of the graph.
+    ///
+    /// Edge directions are ignored. A node is an articulation point if removing it,
+    /// together with its edges, splits its weakly connected component into more pieces.
+    /// These are the single points of failure of a dependency graph.
+    ///
+    /// # Returns
+    /// The node indices of all articulation points, sorted in ascending order.
+    fn articulation_points(&self) -> Vec<usize>;
+
+    /// Finds the bridges (cut edges) of the graph.
+    ///
+    /// Edge directions are ignored. An edge is a bridge if removing it splits its weakly
+    /// connected component into two. Parallel edges between the same two nodes, in
+    /// either direction, back each other up, so they are never bridges.
+    ///
+    /// # Returns
+    /// The bridges as `(source, target)` pairs in their original direction, sorted in
+    /// ascending order.
+    fn bridges(&self) -> Vec<(usize, usize)>;
+
+    // --- Cycles ---
+
+    /// Lazily enumerates all elementary cycles of the graph using Johnson's algorithm.
+    ///
+    /// Unlike `GraphAlgorithms::find_cycle`, which stops at the first back edge, this
+    /// reports every cycle that visits no node twice. Each cycle is reported once, as a
+    /// closed path starting and ending at its smallest node (e.g., `[0, 1, 2, 0]`).
+    /// A self-loop is reported as `[v, v]`, and parallel edges do not yield duplicates.
+    ///
+    /// A graph can contain exponentially many cycles, so both optional caps keep the
+    /// enumeration bounded on dense graphs:
+    ///
+    /// # Arguments
+    /// * `max_length`: If set, only cycles with at most this many edges are reported.
+    /// * `max_cycles`: If set, the iterator stops after this many cycles.
+    fn cycles(
+        &self,
+        max_length: Option<usize>,
+        max_cycles: Option<usize>,
+    ) -> impl Iterator<Item = Vec<usize>> + '_;
+
+    /// Builds the condensation of the graph by contracting every SCC into a single node.
+    ///
+    /// The result is always a DAG, so it can be sorted topologically even when the
+    /// original graph contains feedback loops. Node `c` of the condensation is the
+    /// component with id `c` as returned by
+    /// [`strongly_connected_components`](Self::strongly_connected_components), its
+    /// payload is the sorted list of original node indices in that component, and
+    /// each edge weight counts the original edges between the two components.
+    fn condensation(&self) -> CsmGraph<Vec<usize>, usize>;
+
+    // --- Dominators ---
+
+    /// Computes the dominator tree rooted at the graph's designated root node.
+    ///
+    /// A node `a` dominates `b` if every path from the root to `b` passes through `a`.
+    /// The tree links every node reachable from the root to its immediate dominator.
+    ///
+    /// # Errors
+    /// Returns `GraphError::RootNotFound` if the graph has no root node.
+    fn dominator_tree(&self) -> Result<DominatorTree, GraphError>;
+
+    /// Computes the dominator tree rooted at the given start node.
+    ///
+    /// # Errors
+    /// Returns `GraphError::NodeNotFound` if the start node does not exist.
+    fn dominator_tree_from(&self, root: usize) -> Result<DominatorTree, GraphError>;
+
+    /// Computes the post-dominator tree with respect to the given exit node.
+    ///
+    /// A node `a` post-dominates `b` if every path from `b` to the exit passes through
+    /// `a`. The tree links every node that can reach the exit to its immediate
+    /// post-dominator, and `DominatorTree::root` returns the exit.
+    ///
+    /// # Errors
+    /// Returns `GraphError::NodeNotFound` if the exit node does not exist.
+    fn post_dominator_tree(&self, exit: usize) -> Result<DominatorTree, GraphError>;
+}
@@ -0,0 +1,98 @@
+use crate::{GraphError, GraphView, Weight};
+
+/// The result of a single-source shortest path computation: a tuple
+/// `(distances, predecessors)` of vectors indexed by node. Unreachable nodes
+/// have `None` in both; the source has distance zero and no predecessor.
+pub type ShortestPathTree<W> = (Vec<Option<W>>, Vec<Option<usize>>);
+
+/// Defines pathfinding algorithms that take the edge weights `W` into account.
+///
+/// Unlike the BFS-based methods of `GraphAlgorithms`, which count hops, these
+/// methods minimize the sum of edge weights along a path. They are intended for
+/// implementation on static graph structures like `next_graph::CsmGraph`.
+pub trait WeightedGraphAlgorithms<N, W>: GraphView<N, W>
+where
+    W: Weight,
+{
+    /// Finds the cheapest path from a start to a stop index using Dijkstra's algorithm.
+    ///
+    /// All edge weights must be non-negative.
+    ///
+    /// # Returns
+    /// `Some((path, cost))` with the node indices of the path, including both end
+    /// points, and its total weight. A path from a node to itself is `([n], zero)`.
+    /// Returns `None` if no path exists or if the indices are invalid.
+    fn shortest_path_weighted(
+        &self,
+        start_index: usize,
+        stop_index: usize,
+    ) -> Option<(Vec<usize>, W)>;
+
+    /// Computes the cheapest distance from a start index to every node using Dijkstra's algorithm.
+    ///
+    /// All edge weights must be non-negative.
+    ///
+    /// # Returns
+    /// A vector indexed by node, holding `Some(distance)` for every reachable node and
+    /// `None` for unreachable nodes.
+    ///
+    /// # Errors
+    /// Returns `GraphError::NodeNotFound` if `start_index` does not exist.
+    fn dijkstra_all(&self, start_index: usize) -> Result<Vec<Option<W>>, GraphError>;
+
+    /// Finds the cheapest path from a start to a stop index using A* search.
+    ///
+    /// The `heuristic` estimates the remaining cost from a node to `stop_index`. It
+    /// must never overestimate that cost (i.e. be admissible) for the result to be
+    /// optimal. A good heuristic, such as the Manhattan distance on a grid, lets the
+    /// search explore far fewer nodes than a BFS or Dijkstra. All edge weights must
+    /// be non-negative.
+    ///
+    /// # Returns
+    /// `Some((path, cost))` like [`shortest_path_weighted`](Self::shortest_path_weighted),
+    /// or `None` if no path exists or if the indices are invalid.
+    fn astar(
+        &self,
+        start_index: usize,
+        stop_index: usize,
+        heuristic: impl Fn(usize) -> W,
+    ) -> Option<(Vec<usize>, W)>;
+
+    /// Computes the cheapest distance from a start index to every node using the
+    /// Bellman-Ford algorithm, which also supports negative edge weights.
+    ///
+    /// # Returns
+    /// A [`ShortestPathTree`] with the distance to and predecessor of every node.
+    ///
+    /// # Errors
+    /// - `GraphError::NodeNotFound` if `start_index` does not exist.
+    /// - `GraphError::GraphContainsNegativeCycle` if a negative-weight cycle is reachable
+    ///   from `start_index`, since the distances are then unbounded. The reported node
+    ///   lies on the cycle returned by [`negative_cycle_from`](Self::negative_cycle_from)
+    ///   for the same start index.
+    fn bellman_ford(&self, start_index: usize) -> Result<ShortestPathTree<W>, GraphError>;
+
+    /// Finds a negative-weight cycle that is reachable from a start index, using the
+    /// same Bellman-Ford run as [`bellman_ford`](Self::bellman_ford).
+    ///
+    /// # Returns
+    /// `Some(Vec<usize>)` containing the cycle in the same format as
+    /// `GraphAlgorithms::find_cycle`, or `None` if no negative cycle is reachable from
+    /// `start_index`.
+    ///
+    /// # Errors
+    /// Returns `GraphError::NodeNotFound` if `start_index` does not exist.
+    fn negative_cycle_from(&self, start_index: usize) -> Result<Option<Vec<usize>>, GraphError>;
+
+    /// Finds a single cycle whose edge weights sum to a negative value, anywhere in
+    /// the graph.
+    ///
+    /// Unlike [`negative_cycle_from`](Self::negative_cycle_from), the cycle does not
+    /// have to be reachable from a particular start node.
+    ///
+    /// # Returns
+    /// `Some(Vec<usize>)` containing the sequence of node indices that form the cycle,
+    /// starting and ending with the same node, in the same format as
+    /// `GraphAlgorithms::find_cycle`. Returns `None` if no negative cycle exists.
+    fn find_negative_cycle(&self) -> Option<Vec<usize>>;
+}
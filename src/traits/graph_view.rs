@@ -7,6 +7,16 @@ pub trait GraphView<N, W> {
     fn get_node(&self, index: usize) -> Option<&N>;
     fn number_nodes(&self) -> usize;
 
+    /// Returns an exclusive upper bound for all valid node indices.
+    ///
+    /// Every valid node index is smaller than this bound, so generic algorithms can
+    /// iterate over `0..node_index_bound()` and skip indices for which `contains_node`
+    /// is `false`. For graphs with dense indices this is `number_nodes()`, which is the
+    /// default. Graphs that keep removed nodes as tombstones must override it.
+    fn node_index_bound(&self) -> usize {
+        self.number_nodes()
+    }
+
     // Edge Inspection
     fn contains_edge(&self, a: usize, b: usize) -> bool;
     fn number_edges(&self) -> usize;
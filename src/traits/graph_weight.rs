@@ -0,0 +1,28 @@
+use std::ops::Add;
+
+/// A numeric edge weight that the weighted graph algorithms can sum and compare.
+///
+/// This trait is implemented for all primitive integer and floating-point types,
+/// so edge costs such as `u32`, `u64` or `f64` can be used directly. Comparisons
+/// go through `PartialOrd`; incomparable values such as `f64::NAN` are treated as
+/// equal and should be avoided.
+pub trait Weight: Copy + PartialOrd + Add<Output = Self> {
+    /// Returns the additive identity, i.e. the cost of an empty path.
+    fn zero() -> Self;
+}
+
+macro_rules! impl_weight {
+    ($zero:expr => $($t:ty),*) => {
+        $(
+            impl Weight for $t {
+                #[inline]
+                fn zero() -> Self {
+                    $zero
+                }
+            }
+        )*
+    };
+}
+
+impl_weight!(0 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_weight!(0.0 => f32, f64);
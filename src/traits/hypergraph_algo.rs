@@ -0,0 +1,34 @@
+use crate::{CsmGraph, HypergraphView};
+
+/// Defines the s-line graph family of hypergraph analytics from the NWHy framework.
+///
+/// Two hyperedges are *s-adjacent* when they share at least `s` nodes. An *s-walk*
+/// is a sequence of s-adjacent hyperedges. For `s = 1` this reduces to ordinary
+/// connectivity through shared nodes; larger values of `s` only keep hyperedges
+/// that overlap strongly. An `s` of `0` is treated as `1`.
+pub trait HypergraphAlgorithms<N, E>: HypergraphView<N, E> {
+    /// Builds the s-line graph of the hypergraph.
+    ///
+    /// The returned `CsmGraph` has one node per hyperedge, with the hyperedge index as
+    /// node payload, so node indices in the line graph equal hyperedge indices. Two
+    /// nodes are connected in both directions when their hyperedges share at least
+    /// `s` nodes, and the edge weight is the number of shared nodes. Because the
+    /// result is a regular `CsmGraph`, all `GraphAlgorithms` apply to it directly.
+    fn s_line_graph(&self, s: usize) -> CsmGraph<usize, usize>;
+
+    /// Computes the s-connected components of the hyperedges.
+    ///
+    /// # Returns
+    /// A vector of components, each a sorted list of hyperedge indices. Components
+    /// are ordered by their smallest hyperedge index. A hyperedge with fewer than `s`
+    /// nodes cannot be part of any s-walk and is omitted.
+    fn s_connected_components(&self, s: usize) -> Vec<Vec<usize>>;
+
+    /// Returns the s-distance between two hyperedges: the number of steps in the
+    /// shortest s-walk from `source` to `target`.
+    ///
+    /// Returns `None` if either hyperedge does not exist, has fewer than `s` nodes, or
+    /// if no s-walk connects them. This is consistent with `s_connected_components`,
+    /// so `s_distance(s, e, e)` is `Some(0)` exactly if `e` belongs to a component.
+    fn s_distance(&self, s: usize, source: usize, target: usize) -> Option<usize>;
+}
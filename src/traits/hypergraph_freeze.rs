@@ -0,0 +1,5 @@
+use crate::Hypergraph;
+
+pub trait HypergraphFreezable<N, E> {
+    fn freeze(self) -> Hypergraph<N, E>;
+}
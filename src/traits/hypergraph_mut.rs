@@ -0,0 +1,33 @@
+use crate::{GraphError, HypergraphView};
+
+pub trait HypergraphMut<N, E>: HypergraphView<N, E> {
+    // Node Mutation
+    fn add_node(&mut self, node: N) -> usize;
+    fn update_node(&mut self, index: usize, node: N) -> Result<(), GraphError>;
+
+    /// Removes a node from the hypergraph, effectively "tombstoning" it.
+    ///
+    /// The node is logically removed from every hyperedge it belongs to, but the
+    /// member lists are only compacted when the hypergraph is `freeze`n.
+    ///
+    /// # Errors
+    /// Returns `GraphError::NodeNotFound` if the index is out of bounds or if the
+    /// node at that index has already been removed.
+    fn remove_node(&mut self, index: usize) -> Result<(), GraphError>;
+
+    // Hyperedge Mutation
+
+    /// Adds a hyperedge connecting an arbitrary set of nodes and returns its stable index.
+    ///
+    /// Duplicate node indices in `nodes` are collapsed into a single membership.
+    ///
+    /// # Errors
+    /// Returns `GraphError::NodeNotFound` for the first index in `nodes` that does
+    /// not exist or has been removed.
+    fn add_hyperedge(&mut self, nodes: &[usize], payload: E) -> Result<usize, GraphError>;
+    fn update_hyperedge(&mut self, index: usize, payload: E) -> Result<(), GraphError>;
+    fn remove_hyperedge(&mut self, index: usize) -> Result<(), GraphError>;
+
+    // Graph-wide Mutation
+    fn clear(&mut self);
+}
@@ -0,0 +1,14 @@
+use crate::{GraphError, HypergraphView};
+
+pub trait HypergraphTraversal<N, E>: HypergraphView<N, E> {
+    // --- Traversal ---
+
+    /// Returns an iterator over the member nodes of hyperedge `e`, in ascending index order.
+    ///
+    /// # Returns
+    /// A `Result` containing an iterator that yields the `usize` indices of the member nodes.
+    fn hyperedge_nodes(&self, e: usize) -> Result<impl Iterator<Item = usize> + '_, GraphError>;
+
+    /// Returns an iterator over the hyperedges that node `a` is a member of, in ascending index order.
+    fn node_hyperedges(&self, a: usize) -> Result<impl Iterator<Item = usize> + '_, GraphError>;
+}
@@ -0,0 +1,5 @@
+use crate::DynamicHypergraph;
+
+pub trait HypergraphUnfreezable<N, E> {
+    fn unfreeze(self) -> DynamicHypergraph<N, E>;
+}
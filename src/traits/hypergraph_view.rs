@@ -0,0 +1,22 @@
+pub trait HypergraphView<N, E> {
+    // State Inspection
+    fn is_frozen(&self) -> bool;
+
+    // Node Inspection
+    fn contains_node(&self, index: usize) -> bool;
+    fn get_node(&self, index: usize) -> Option<&N>;
+    fn number_nodes(&self) -> usize;
+
+    // Hyperedge Inspection
+    fn contains_hyperedge(&self, index: usize) -> bool;
+    fn get_hyperedge(&self, index: usize) -> Option<&E>;
+    fn number_hyperedges(&self) -> usize;
+
+    /// Checks if `node` is a member of the hyperedge at index `hyperedge`.
+    /// Returns `false` if either index does not exist.
+    fn is_incident(&self, node: usize, hyperedge: usize) -> bool;
+
+    /// Returns the total number of node-hyperedge incidences, i.e. the sum of all hyperedge sizes.
+    /// This is the number of edges in the bipartite representation of the hypergraph.
+    fn number_incidences(&self) -> usize;
+}
@@ -1,6 +1,21 @@
+pub mod directed_hypergraph_freeze;
+pub mod directed_hypergraph_mut;
+pub mod directed_hypergraph_traversal;
+pub mod directed_hypergraph_unfreeze;
 pub mod graph_algo;
+pub mod graph_algo_causal;
+pub mod graph_algo_dag;
+pub mod graph_algo_structural;
+pub mod graph_algo_weighted;
 pub mod graph_freeze;
 pub mod graph_mut;
 pub mod graph_traversal;
 pub mod graph_unfreeze;
 pub mod graph_view;
+pub mod graph_weight;
+pub mod hypergraph_algo;
+pub mod hypergraph_freeze;
+pub mod hypergraph_mut;
+pub mod hypergraph_traversal;
+pub mod hypergraph_unfreeze;
+pub mod hypergraph_view;
@@ -0,0 +1,87 @@
+use crate::GraphError;
+
+// Marks nodes that are not reachable from the root and thus have no dominators.
+pub(crate) const UNREACHABLE: usize = usize::MAX;
+
+/// The dominator tree of a graph, rooted at a start node.
+///
+/// A node `a` dominates a node `b` if every path from the root to `b` passes through
+/// `a`. The immediate dominator of `b` is its closest strict dominator, and linking
+/// every node to its immediate dominator forms a tree rooted at the start node.
+///
+/// For a post-dominator tree, all paths are followed backwards to an exit node, so
+/// `a` post-dominates `b` if every path from `b` to the exit passes through `a`.
+///
+/// A `DominatorTree` is built with `StructuralGraphAlgorithms::dominator_tree` and its
+/// sibling methods. Nodes that cannot be reached from the root are not part of it.
+pub struct DominatorTree {
+    root: usize,
+    // The immediate dominator of every node. The root points to itself, and
+    // unreachable nodes are marked with `UNREACHABLE`.
+    idoms: Vec<usize>,
+}
+
+impl DominatorTree {
+    // Internal helper for the dominator algorithm.
+    pub(crate) fn new(root: usize, idoms: Vec<usize>) -> Self {
+        Self { root, idoms }
+    }
+}
+
+impl DominatorTree {
+    /// Returns the root (or, for post-dominators, the exit node) of the tree.
+    pub fn root(&self) -> usize {
+        self.root
+    }
+
+    /// Returns the number of nodes covered by the tree, including unreachable ones.
+    pub fn number_nodes(&self) -> usize {
+        self.idoms.len()
+    }
+
+    /// Checks if a node is reachable from the root and therefore part of the tree.
+    pub fn is_reachable(&self, node: usize) -> bool {
+        self.idoms
+            .get(node)
+            .is_some_and(|&idom| idom != UNREACHABLE)
+    }
+
+    /// Returns the immediate dominator of a node.
+    ///
+    /// Returns `None` for the root, for unreachable nodes and for out-of-bounds indices.
+    pub fn immediate_dominator(&self, node: usize) -> Option<usize> {
+        match self.idoms.get(node) {
+            Some(&idom) if idom != UNREACHABLE && node != self.root => Some(idom),
+            _ => None,
+        }
+    }
+
+    /// Returns an iterator over all dominators of a node, from the node itself up to
+    /// the root.
+    ///
+    /// Every reachable node dominates itself, so the node is always the first item.
+    /// The iterator is empty for unreachable nodes.
+    ///
+    /// # Errors
+    /// Returns `GraphError::NodeNotFound` if the index is out of bounds.
+    pub fn dominators(&self, node: usize) -> Result<impl Iterator<Item = usize> + '_, GraphError> {
+        if node >= self.idoms.len() {
+            return Err(GraphError::NodeNotFound(node));
+        }
+
+        let first = self.is_reachable(node).then_some(node);
+        Ok(std::iter::successors(first, move |&n| {
+            self.immediate_dominator(n)
+        }))
+    }
+
+    /// Checks if `a` dominates `b`, i.e., if every path from the root to `b` passes
+    /// through `a`.
+    ///
+    /// Every reachable node dominates itself. Returns `false` if either node is
+    /// unreachable or out of bounds.
+    pub fn dominates(&self, a: usize, b: usize) -> bool {
+        self.dominators(b)
+            .is_ok_and(|mut dominators| dominators.any(|d| d == a))
+    }
+}
@@ -1,18 +1,5 @@
-use crate::{CsmGraph, GraphAlgorithms, GraphView};
+use crate::{CsmGraph, GraphAlgorithms, GraphView, TraversalWorkspace};
 use std::collections::VecDeque;
-use std::slice;
-
-/// Private enum representing the state of a node during a DFS traversal.
-/// It is not part of the public API.
-#[derive(Clone, Copy, PartialEq, Debug)]
-pub enum NodeState {
-    /// The node has not yet been visited.
-    Unvisited,
-    /// The node is currently in the recursion stack (being visited).
-    VisitingInProgress,
-    /// The node and all its descendants have been fully visited and are known to be cycle-free.
-    Visited,
-}
 
 impl<N, W> GraphAlgorithms<N, W> for CsmGraph<N, W>
 where
@@ -39,69 +26,10 @@ where
     ///   number of edges, as each node and edge is visited exactly once.
     /// - **Space Complexity:** O(V) for storing node states, predecessors, and the DFS stack.
     ///
+    /// This allocates a fresh `TraversalWorkspace`; use `find_cycle_with_workspace` to
+    /// reuse the buffers across calls.
     fn find_cycle(&self) -> Option<Vec<usize>> {
-        let num_nodes = self.number_nodes();
-        if num_nodes == 0 {
-            return None;
-        }
-
-        let mut states = vec![NodeState::Unvisited; num_nodes];
-        let mut predecessors = vec![None; num_nodes];
-
-        for i in 0..num_nodes {
-            if states[i] == NodeState::Unvisited {
-                // We use the concrete `slice::Iter` type to avoid Box and dynamic dispatch.
-                let mut stack: Vec<(usize, slice::Iter<'_, usize>)> = Vec::new();
-
-                // By accessing the internal fields directly, we bypass the opaque `impl Trait`
-                // returned by the `outbound_edges` trait method.
-                let start = self.forward_edges.offsets[i];
-                let end = self.forward_edges.offsets[i + 1];
-                let neighbors = self.forward_edges.targets[start..end].iter();
-
-                states[i] = NodeState::VisitingInProgress;
-                stack.push((i, neighbors));
-
-                while let Some((u_ref, neighbors)) = stack.last_mut() {
-                    let u = *u_ref;
-
-                    if let Some(&v) = neighbors.next() {
-                        if states[v] == NodeState::VisitingInProgress {
-                            // --- Cycle Found: Reconstruct the Path ---
-                            let mut path = vec![u];
-                            let mut current = u;
-
-                            while let Some(predecessor) = predecessors[current] {
-                                path.push(predecessor);
-                                if predecessor == v {
-                                    break;
-                                }
-                                current = predecessor;
-                            }
-                            path.reverse();
-                            path.push(v); // Make the cycle explicit: [v, ..., u, v]
-                            return Some(path);
-                        }
-
-                        if states[v] == NodeState::Unvisited {
-                            predecessors[v] = Some(u);
-                            states[v] = NodeState::VisitingInProgress;
-
-                            let v_start = self.forward_edges.offsets[v];
-                            let v_end = self.forward_edges.offsets[v + 1];
-                            let v_neighbors = self.forward_edges.targets[v_start..v_end].iter();
-                            stack.push((v, v_neighbors));
-                        }
-                    } else {
-                        // If the neighbor iterator is exhausted, we are done with this node.
-                        states[u] = NodeState::Visited;
-                        stack.pop();
-                    }
-                }
-            }
-        }
-
-        None // No cycles found after checking all nodes.
+        self.find_cycle_with_workspace(&mut TraversalWorkspace::new())
     }
 
     /// Checks if the graph contains any directed cycles.
@@ -173,84 +101,22 @@ where
     }
 
     /// Returns the length of the shortest path (in number of nodes) from a start to a stop index.
+    ///
+    /// This allocates a fresh `TraversalWorkspace`; use `shortest_path_len_with_workspace`
+    /// to reuse the buffers across calls.
     fn shortest_path_len(&self, start_index: usize, stop_index: usize) -> Option<usize> {
-        if !self.contains_node(start_index) || !self.contains_node(stop_index) {
-            return None;
-        }
-        if start_index == stop_index {
-            return Some(1);
-        }
-
-        let mut queue = VecDeque::new();
-        let mut visited = vec![false; self.number_nodes()];
-
-        queue.push_back((start_index, 1)); // (node, path_length)
-        visited[start_index] = true;
-
-        while let Some((current_node, current_len)) = queue.pop_front() {
-            //  Access CSR arrays directly.
-            let start = self.forward_edges.offsets[current_node];
-            let end = self.forward_edges.offsets[current_node + 1];
-            for &neighbor in &self.forward_edges.targets[start..end] {
-                if neighbor == stop_index {
-                    return Some(current_len + 1);
-                }
-                if !visited[neighbor] {
-                    visited[neighbor] = true;
-                    queue.push_back((neighbor, current_len + 1));
-                }
-            }
-        }
-        None
+        self.shortest_path_len_with_workspace(
+            start_index,
+            stop_index,
+            &mut TraversalWorkspace::new(),
+        )
     }
 
     /// Finds the complete shortest path from a start to a stop index.
+    ///
+    /// This allocates a fresh `TraversalWorkspace`; use `shortest_path_with_workspace`
+    /// to reuse the buffers across calls.
     fn shortest_path(&self, start_index: usize, stop_index: usize) -> Option<Vec<usize>> {
-        if !self.contains_node(start_index) || !self.contains_node(stop_index) {
-            return None;
-        }
-        if start_index == stop_index {
-            return Some(vec![start_index]);
-        }
-
-        let mut queue = VecDeque::new();
-        let mut predecessors = vec![None; self.number_nodes()];
-        let mut visited = vec![false; self.number_nodes()];
-
-        queue.push_back(start_index);
-        visited[start_index] = true;
-
-        let mut found = false;
-        'bfs_loop: while let Some(current_node) = queue.pop_front() {
-            //  Access CSR arrays directly.
-            let start = self.forward_edges.offsets[current_node];
-            let end = self.forward_edges.offsets[current_node + 1];
-            for &neighbor in &self.forward_edges.targets[start..end] {
-                if !visited[neighbor] {
-                    visited[neighbor] = true;
-                    predecessors[neighbor] = Some(current_node);
-                    queue.push_back(neighbor);
-
-                    if neighbor == stop_index {
-                        found = true;
-                        break 'bfs_loop;
-                    }
-                }
-            }
-        }
-
-        if !found {
-            return None;
-        }
-
-        // Reconstruct path by walking backwards from the stop index.
-        let mut path = Vec::new();
-        let mut current = Some(stop_index);
-        while let Some(curr_index) = current {
-            path.push(curr_index);
-            current = predecessors[curr_index];
-        }
-        path.reverse();
-        Some(path)
+        self.shortest_path_with_workspace(start_index, stop_index, &mut TraversalWorkspace::new())
     }
 }
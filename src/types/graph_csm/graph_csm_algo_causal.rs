@@ -0,0 +1,464 @@
+use crate::types::graph_csm::CsrAdjacency;
+use crate::{CausalGraphAlgorithms, CsmGraph, GraphAlgorithms, GraphError, GraphView};
+
+impl<N, W> CausalGraphAlgorithms<N, W> for CsmGraph<N, W>
+where
+    W: Default,
+{
+    /// Returns all ancestors of a node with an iterative DFS over the `backward_edges`.
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** O(V + E) in the worst case, but only the ancestors and their
+    ///   edges are visited.
+    /// - **Space Complexity:** O(V) for the visited flags.
+    fn ancestors(&self, node: usize) -> Result<Vec<usize>, GraphError> {
+        if !self.contains_node(node) {
+            return Err(GraphError::NodeNotFound(node));
+        }
+
+        let marked = mark_reachable(&self.backward_edges, node);
+        Ok(collect_marked(&marked, node))
+    }
+
+    /// Returns all descendants of a node with an iterative DFS over the `forward_edges`.
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** O(V + E) in the worst case, but only the descendants and
+    ///   their edges are visited.
+    /// - **Space Complexity:** O(V) for the visited flags.
+    fn descendants(&self, node: usize) -> Result<Vec<usize>, GraphError> {
+        if !self.contains_node(node) {
+            return Err(GraphError::NodeNotFound(node));
+        }
+
+        let marked = mark_reachable(&self.forward_edges, node);
+        Ok(collect_marked(&marked, node))
+    }
+
+    /// Finds the lowest common ancestors by intersecting the ancestor sets of both nodes.
+    ///
+    /// In a DAG, a common ancestor with a common ancestor among its descendants also
+    /// has one among its direct successors: the successor on the path is an ancestor
+    /// of both nodes, too. So a common ancestor is lowest exactly when none of its
+    /// successors is a common ancestor.
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** O(V + E) for the acyclicity check and both searches.
+    /// - **Space Complexity:** O(V) for the ancestor flags.
+    fn lowest_common_ancestors(&self, a: usize, b: usize) -> Result<Vec<usize>, GraphError> {
+        validate_dag(self, [a, b])?;
+
+        // Both searches include their start node, which counts as its own ancestor.
+        let ancestors_a = mark_reachable(&self.backward_edges, a);
+        let ancestors_b = mark_reachable(&self.backward_edges, b);
+        let is_common = |u: usize| ancestors_a[u] && ancestors_b[u];
+
+        let lowest = (0..self.number_nodes())
+            .filter(|&u| is_common(u))
+            .filter(|&u| {
+                //  Access CSR arrays directly.
+                let start = self.forward_edges.offsets[u];
+                let end = self.forward_edges.offsets[u + 1];
+                !self.forward_edges.targets[start..end]
+                    .iter()
+                    .any(|&v| is_common(v))
+            })
+            .collect();
+
+        Ok(lowest)
+    }
+
+    /// Checks d-separation with the Bayes-ball algorithm.
+    ///
+    /// A ball is sent from every node in `xs` and moves along both `forward_edges` and
+    /// `backward_edges`. Its state is the node plus the direction it arrived from:
+    ///
+    /// - Arriving from a child ("up"), an unobserved node passes the ball on to its
+    ///   parents and children (chain or fork).
+    /// - Arriving from a parent ("down"), an unobserved node passes it on to its
+    ///   children (chain), and a node that is observed or has an observed descendant
+    ///   bounces it back to its parents (activated collider).
+    ///
+    /// `xs` and `ys` are d-separated if the ball reaches no unobserved node in `ys`.
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** O(V + E), as every node is visited at most once per direction.
+    /// - **Space Complexity:** O(V) for the observed, ancestor and visited flags.
+    fn is_d_separated(
+        &self,
+        xs: &[usize],
+        ys: &[usize],
+        given: &[usize],
+    ) -> Result<bool, GraphError> {
+        validate_dag(self, xs.iter().chain(ys).chain(given).copied())?;
+
+        let no_cut = cut_outgoing(self.number_nodes(), &[]);
+        Ok(bayes_ball(self, xs, ys, given, &no_cut))
+    }
+
+    /// Collects the Markov blanket from the `backward_edges` of the node and its children.
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** O(V + E) in the worst case, dominated by the in-degrees of
+    ///   the children.
+    /// - **Space Complexity:** O(V) for the membership flags.
+    fn markov_blanket(&self, node: usize) -> Result<Vec<usize>, GraphError> {
+        if !self.contains_node(node) {
+            return Err(GraphError::NodeNotFound(node));
+        }
+
+        let mut in_blanket = vec![false; self.number_nodes()];
+        let parents_of = |u: usize| {
+            let start = self.backward_edges.offsets[u];
+            let end = self.backward_edges.offsets[u + 1];
+            &self.backward_edges.targets[start..end]
+        };
+
+        for &parent in parents_of(node) {
+            in_blanket[parent] = true;
+        }
+        let start = self.forward_edges.offsets[node];
+        let end = self.forward_edges.offsets[node + 1];
+        for &child in &self.forward_edges.targets[start..end] {
+            in_blanket[child] = true;
+            for &spouse in parents_of(child) {
+                in_blanket[spouse] = true;
+            }
+        }
+
+        Ok(collect_marked(&in_blanket, node))
+    }
+
+    /// Finds a minimal backdoor adjustment set by shrinking the parents of `x`.
+    ///
+    /// The parents of `x` are never descendants of `x`, and every backdoor path leaves
+    /// `x` through one of them as a chain or fork node, so they always form a valid set
+    /// unless `y` is a parent itself. Each parent is then dropped in turn if the rest
+    /// still d-separates `x` and `y` once the outgoing edges of `x` are cut. For
+    /// d-separators, a set from which no single node can be removed is minimal.
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** O(P * (V + E)) for P parents of `x`.
+    /// - **Space Complexity:** O(V) for the Bayes-ball state.
+    fn backdoor_adjustment_set(
+        &self,
+        x: usize,
+        y: usize,
+    ) -> Result<Option<Vec<usize>>, GraphError> {
+        validate_dag(self, [x, y])?;
+
+        let mut set = parents(self, x);
+        if x == y || set.binary_search(&y).is_ok() {
+            return Ok(None);
+        }
+
+        let cut = cut_outgoing(self.number_nodes(), &[x]);
+        for i in (0..set.len()).rev() {
+            let parent = set.remove(i);
+            if !bayes_ball(self, &[x], &[y], &set, &cut) {
+                set.insert(i, parent);
+            }
+        }
+
+        Ok(Some(set))
+    }
+
+    /// Enumerates all minimal backdoor adjustment sets by increasing size.
+    ///
+    /// Every minimal set lies within the ancestors of `x` and `y` and, by the criterion,
+    /// outside the descendants of `x`. All subsets of these candidates, up to
+    /// `max_size` nodes, are tested from the smallest to the largest, skipping supersets
+    /// of sets already found. The search stops once `max_sets` sets are found.
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** O(2^C * (V + E)) in the worst case, for C candidates, or
+    ///   O(C^k * (V + E)) with `max_size` set to k.
+    /// - **Space Complexity:** O(V) plus the size of the result.
+    fn backdoor_adjustment_sets(
+        &self,
+        x: usize,
+        y: usize,
+        max_size: Option<usize>,
+        max_sets: Option<usize>,
+    ) -> Result<Vec<Vec<usize>>, GraphError> {
+        validate_dag(self, [x, y])?;
+
+        if x == y || max_sets == Some(0) || parents(self, x).binary_search(&y).is_ok() {
+            return Ok(Vec::new());
+        }
+
+        let descendants = mark_reachable(&self.forward_edges, x);
+        let ancestors_x = mark_reachable(&self.backward_edges, x);
+        let ancestors_y = mark_reachable(&self.backward_edges, y);
+        // `x` is its own descendant, so it is excluded along with them.
+        let candidates: Vec<usize> = (0..self.number_nodes())
+            .filter(|&u| (ancestors_x[u] || ancestors_y[u]) && !descendants[u] && u != y)
+            .collect();
+
+        let cut = cut_outgoing(self.number_nodes(), &[x]);
+        let mut sets: Vec<Vec<usize>> = Vec::new();
+        let max_size = max_size.map_or(candidates.len(), |max| max.min(candidates.len()));
+        for size in 0..=max_size {
+            let mut combination: Vec<usize> = (0..size).collect();
+            loop {
+                let set: Vec<usize> = combination.iter().map(|&i| candidates[i]).collect();
+                // Both sets are sorted, so a binary search suffices for the subset test.
+                let is_superset = sets
+                    .iter()
+                    .any(|found| found.iter().all(|u| set.binary_search(u).is_ok()));
+                if !is_superset && bayes_ball(self, &[x], &[y], &set, &cut) {
+                    sets.push(set);
+                    if max_sets == Some(sets.len()) {
+                        return Ok(sets);
+                    }
+                }
+                if !next_combination(&mut combination, candidates.len()) {
+                    break;
+                }
+            }
+
+            // If no adjustment is needed at all, every other set is a superset.
+            if sets.first().is_some_and(Vec::is_empty) {
+                break;
+            }
+        }
+
+        Ok(sets)
+    }
+
+    /// Checks the frontdoor criterion with one forward search and two Bayes-ball runs.
+    ///
+    /// 1. A forward DFS from `x` that never enters a mediator must not reach `y`.
+    /// 2. `x` and the mediators must be d-separated once the outgoing edges of `x`
+    ///    are cut, so that no backdoor path from `x` reaches a mediator.
+    /// 3. The mediators and `y` must be d-separated given `x` once the outgoing edges
+    ///    of the mediators are cut, so that `x` blocks their backdoor paths to `y`.
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** O(V + E).
+    /// - **Space Complexity:** O(V) for the search state.
+    fn is_frontdoor_adjustment_set(
+        &self,
+        x: usize,
+        y: usize,
+        mediators: &[usize],
+    ) -> Result<bool, GraphError> {
+        validate_dag(self, [x, y].into_iter().chain(mediators.iter().copied()))?;
+
+        let num_nodes = self.number_nodes();
+        let is_mediator = cut_outgoing(num_nodes, mediators);
+        if x == y || is_mediator[x] || is_mediator[y] {
+            return Ok(false);
+        }
+
+        // 1. The mediators intercept every directed path from `x` to `y`.
+        let mut reached = vec![false; num_nodes];
+        reached[x] = true;
+        let mut stack = vec![x];
+        while let Some(u) = stack.pop() {
+            //  Access CSR arrays directly.
+            let start = self.forward_edges.offsets[u];
+            let end = self.forward_edges.offsets[u + 1];
+            for &v in &self.forward_edges.targets[start..end] {
+                if !is_mediator[v] && !reached[v] {
+                    reached[v] = true;
+                    stack.push(v);
+                }
+            }
+        }
+        if reached[y] {
+            return Ok(false);
+        }
+
+        // 2. There is no unblocked backdoor path from `x` to a mediator.
+        let cut = cut_outgoing(num_nodes, &[x]);
+        if !bayes_ball(self, &[x], mediators, &[], &cut) {
+            return Ok(false);
+        }
+
+        // 3. `x` blocks all backdoor paths from the mediators to `y`.
+        Ok(bayes_ball(self, mediators, &[y], &[x], &is_mediator))
+    }
+}
+
+/// Checks that all given nodes exist and that the graph is a DAG.
+fn validate_dag<N, W>(
+    graph: &CsmGraph<N, W>,
+    nodes: impl IntoIterator<Item = usize>,
+) -> Result<(), GraphError>
+where
+    W: Default,
+{
+    if let Some(node) = nodes.into_iter().find(|&n| !graph.contains_node(n)) {
+        return Err(GraphError::NodeNotFound(node));
+    }
+    if graph.has_cycle() {
+        return Err(GraphError::GraphContainsCycle);
+    }
+    Ok(())
+}
+
+/// Returns the sorted, deduplicated parents of a node.
+fn parents<N, W>(graph: &CsmGraph<N, W>, node: usize) -> Vec<usize>
+where
+    W: Default,
+{
+    let start = graph.backward_edges.offsets[node];
+    let end = graph.backward_edges.offsets[node + 1];
+    let mut parents = graph.backward_edges.targets[start..end].to_vec();
+    parents.sort_unstable();
+    parents.dedup();
+    parents
+}
+
+/// Returns the flags for `bayes_ball` that cut the outgoing edges of the given nodes.
+fn cut_outgoing(num_nodes: usize, nodes: &[usize]) -> Vec<bool> {
+    let mut cut = vec![false; num_nodes];
+    for &u in nodes {
+        cut[u] = true;
+    }
+    cut
+}
+
+/// Advances `indices` to the next k-combination of `0..n` in lexicographic order.
+///
+/// Returns `false` once all combinations have been visited.
+fn next_combination(indices: &mut [usize], n: usize) -> bool {
+    let k = indices.len();
+    for i in (0..k).rev() {
+        if indices[i] < n - k + i {
+            indices[i] += 1;
+            for j in i + 1..k {
+                indices[j] = indices[j - 1] + 1;
+            }
+            return true;
+        }
+    }
+    false
+}
+
+/// Runs the Bayes-ball algorithm and returns whether `xs` and `ys` are d-separated by
+/// `given`, in the graph without the outgoing edges of every node `u` with `cut[u]`.
+///
+/// Cutting edges allows checking the backdoor and frontdoor criteria, which only
+/// consider paths that enter a node instead of leaving it.
+fn bayes_ball<N, W>(
+    graph: &CsmGraph<N, W>,
+    xs: &[usize],
+    ys: &[usize],
+    given: &[usize],
+    cut: &[bool],
+) -> bool
+where
+    W: Default,
+{
+    let num_nodes = graph.number_nodes();
+    let mut observed = vec![false; num_nodes];
+    for &z in given {
+        observed[z] = true;
+    }
+    let mut is_target = vec![false; num_nodes];
+    for &y in ys {
+        is_target[y] = true;
+    }
+
+    // Phase 1: A collider is active if it or any of its descendants is observed,
+    // i.e. if it is an ancestor of an observed node (or observed itself).
+    let mut activates_collider = observed.clone();
+    let mut stack: Vec<usize> = given.to_vec();
+    while let Some(u) = stack.pop() {
+        let start = graph.backward_edges.offsets[u];
+        let end = graph.backward_edges.offsets[u + 1];
+        for &v in &graph.backward_edges.targets[start..end] {
+            if !cut[v] && !activates_collider[v] {
+                activates_collider[v] = true;
+                stack.push(v);
+            }
+        }
+    }
+
+    // Phase 2: Bounce the ball. The flag is `true` if the ball arrived from a child.
+    let mut visited_up = vec![false; num_nodes];
+    let mut visited_down = vec![false; num_nodes];
+    let mut balls: Vec<(usize, bool)> = xs.iter().map(|&x| (x, true)).collect();
+    while let Some((u, from_child)) = balls.pop() {
+        let visited = if from_child {
+            &mut visited_up
+        } else {
+            &mut visited_down
+        };
+        if visited[u] {
+            continue;
+        }
+        visited[u] = true;
+
+        if !observed[u] && is_target[u] {
+            return false;
+        }
+
+        let pass_to_parents = if from_child {
+            !observed[u]
+        } else {
+            activates_collider[u]
+        };
+        let pass_to_children = !observed[u] && !cut[u];
+
+        //  Access CSR arrays directly.
+        if pass_to_parents {
+            let start = graph.backward_edges.offsets[u];
+            let end = graph.backward_edges.offsets[u + 1];
+            for &parent in &graph.backward_edges.targets[start..end] {
+                if !cut[parent] {
+                    balls.push((parent, true));
+                }
+            }
+        }
+        if pass_to_children {
+            let start = graph.forward_edges.offsets[u];
+            let end = graph.forward_edges.offsets[u + 1];
+            for &child in &graph.forward_edges.targets[start..end] {
+                balls.push((child, false));
+            }
+        }
+    }
+
+    true
+}
+
+/// Marks every node reachable from `start` in the given adjacency, including `start`.
+fn mark_reachable<W>(adjacency: &CsrAdjacency<W>, start: usize) -> Vec<bool> {
+    let mut marked = vec![false; adjacency.offsets.len() - 1];
+    marked[start] = true;
+
+    let mut stack = vec![start];
+    while let Some(u) = stack.pop() {
+        let begin = adjacency.offsets[u];
+        let end = adjacency.offsets[u + 1];
+        for &v in &adjacency.targets[begin..end] {
+            if !marked[v] {
+                marked[v] = true;
+                stack.push(v);
+            }
+        }
+    }
+
+    marked
+}
+
+/// Collects the indices of all marked nodes, except `node` itself.
+fn collect_marked(marked: &[bool], node: usize) -> Vec<usize> {
+    marked
+        .iter()
+        .enumerate()
+        .filter(|&(i, &m)| m && i != node)
+        .map(|(i, _)| i)
+        .collect()
+}
@@ -0,0 +1,264 @@
+use crate::{
+    CsmGraph, DagAlgorithms, DynamicGraph, Freezable, GraphAlgorithms, GraphError, GraphView,
+    TransitiveClosure, Weight,
+};
+
+impl<N, W> DagAlgorithms<N, W> for CsmGraph<N, W>
+where
+    W: Default,
+{
+    /// Computes the topological generations with a layered version of Kahn's algorithm.
+    ///
+    /// Instead of a single queue, the nodes with zero in-degree are collected into a
+    /// frontier. Removing the whole frontier at once yields the next one, so every
+    /// frontier becomes one generation.
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** O(V + E), plus sorting each generation.
+    /// - **Space Complexity:** O(V) for the in-degrees and generations.
+    fn topological_generations(&self) -> Result<Vec<Vec<usize>>, GraphError> {
+        let num_nodes = self.number_nodes();
+
+        //  Access CSR arrays directly.
+        let mut in_degrees = vec![0; num_nodes];
+        for &v in &self.forward_edges.targets {
+            in_degrees[v] += 1;
+        }
+
+        let mut frontier: Vec<usize> = (0..num_nodes).filter(|&u| in_degrees[u] == 0).collect();
+        let mut generations = Vec::new();
+        let mut num_sorted = 0;
+
+        while !frontier.is_empty() {
+            let mut next_frontier = Vec::new();
+            for &u in &frontier {
+                let start = self.forward_edges.offsets[u];
+                let end = self.forward_edges.offsets[u + 1];
+                for &v in &self.forward_edges.targets[start..end] {
+                    in_degrees[v] -= 1;
+                    if in_degrees[v] == 0 {
+                        next_frontier.push(v);
+                    }
+                }
+            }
+
+            next_frontier.sort_unstable();
+            num_sorted += frontier.len();
+            generations.push(frontier);
+            frontier = next_frontier;
+        }
+
+        // Nodes on or behind a cycle never reach an in-degree of zero.
+        if num_sorted == num_nodes {
+            Ok(generations)
+        } else {
+            Err(GraphError::GraphContainsCycle)
+        }
+    }
+
+    /// Computes the transitive closure by dynamic programming over the topological order.
+    ///
+    /// Nodes are processed in reverse topological order, so when a node is reached the
+    /// rows of all its successors are already complete. A node's row is then the union
+    /// of each successor and that successor's row, computed 64 nodes at a time.
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** O(V + E * V / 64) for the row unions.
+    /// - **Space Complexity:** O(V² / 64) words for the bitset rows.
+    fn transitive_closure(&self) -> Result<TransitiveClosure, GraphError> {
+        let order = self
+            .topological_sort()
+            .ok_or(GraphError::GraphContainsCycle)?;
+
+        let mut closure = TransitiveClosure::with_nodes(self.number_nodes());
+        for &u in order.iter().rev() {
+            //  Access CSR arrays directly.
+            let start = self.forward_edges.offsets[u];
+            let end = self.forward_edges.offsets[u + 1];
+            for &v in &self.forward_edges.targets[start..end] {
+                closure.insert(u, v);
+                closure.union_row(u, v);
+            }
+        }
+
+        Ok(closure)
+    }
+
+    /// Computes the transitive reduction from the transitive closure.
+    ///
+    /// In a DAG, an edge `u -> v` is redundant exactly when `v` is reachable from
+    /// another successor `w` of `u`. For every node, the closure rows of all its
+    /// successors are merged into a single "covered" bitset, and only the edges to
+    /// successors outside that set are kept.
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** O(V + E * V / 64), dominated by the closure and row unions.
+    /// - **Space Complexity:** O(V² / 64) words for the closure.
+    fn transitive_reduction(&self) -> Result<CsmGraph<N, W>, GraphError>
+    where
+        N: Clone,
+        W: Clone + Default,
+    {
+        let closure = self.transitive_closure()?;
+        let num_nodes = self.number_nodes();
+
+        let mut covered = vec![0u64; num_nodes.div_ceil(64)];
+        let mut edges = Vec::with_capacity(num_nodes);
+
+        for u in 0..num_nodes {
+            let start = self.forward_edges.offsets[u];
+            let end = self.forward_edges.offsets[u + 1];
+            let targets = &self.forward_edges.targets[start..end];
+            let weights = &self.forward_edges.weights[start..end];
+
+            covered.fill(0);
+            for &w in targets {
+                for (c, &r) in covered.iter_mut().zip(closure.row(w)) {
+                    *c |= r;
+                }
+            }
+
+            let mut kept = Vec::new();
+            for (&v, weight) in targets.iter().zip(weights) {
+                let bit = 1 << (v % 64);
+                if covered[v / 64] & bit == 0 {
+                    kept.push((v, weight.clone()));
+                    // Mark `v` as covered so that parallel edges are only kept once.
+                    covered[v / 64] |= bit;
+                }
+            }
+            edges.push(kept);
+        }
+
+        let nodes = self.nodes.iter().cloned().map(Some).collect();
+        Ok(DynamicGraph::from_parts(nodes, edges, self.root_index).freeze())
+    }
+
+    /// Finds the longest path over the raw edge weights.
+    ///
+    /// This is `critical_path` with the identity as weight mapping.
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** O(V + E).
+    /// - **Space Complexity:** O(V) for distances and predecessors.
+    fn longest_path(&self) -> Result<(Vec<usize>, W), GraphError>
+    where
+        W: Weight,
+    {
+        self.critical_path(|&weight| weight)
+    }
+
+    /// Finds the critical path with a dynamic program over the topological order.
+    ///
+    /// Every node starts with a path of length zero that consists only of itself. Nodes
+    /// are processed in topological order, relaxing each outgoing edge `u -> v` to
+    /// `dist[u] + weights(w)` if that is longer than `dist[v]`. The path is rebuilt
+    /// from the node with the largest distance by following the recorded predecessors.
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** O(V + E).
+    /// - **Space Complexity:** O(V) for distances and predecessors.
+    fn critical_path<C>(&self, weights: impl Fn(&W) -> C) -> Result<(Vec<usize>, C), GraphError>
+    where
+        C: Weight,
+    {
+        let order = self
+            .topological_sort()
+            .ok_or(GraphError::GraphContainsCycle)?;
+
+        let mut distances = vec![C::zero(); self.number_nodes()];
+        let mut predecessors = vec![None; self.number_nodes()];
+        for &u in &order {
+            //  Access CSR arrays directly.
+            let start = self.forward_edges.offsets[u];
+            let end = self.forward_edges.offsets[u + 1];
+            for i in start..end {
+                let v = self.forward_edges.targets[i];
+                let candidate = distances[u] + weights(&self.forward_edges.weights[i]);
+                if candidate > distances[v] {
+                    distances[v] = candidate;
+                    predecessors[v] = Some(u);
+                }
+            }
+        }
+
+        Ok(trace_longest(&order, &distances, &predecessors))
+    }
+
+    /// Finds the node-weighted critical path with a dynamic program over the
+    /// topological order.
+    ///
+    /// This is the forward pass of the Critical Path Method (CPM): the finish time of
+    /// a task is its duration plus the latest finish time of its predecessors. The
+    /// critical path ends at the task with the latest finish time.
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** O(V + E).
+    /// - **Space Complexity:** O(V) for finish times and predecessors.
+    fn critical_path_by_durations<C>(
+        &self,
+        durations: impl Fn(usize) -> C,
+    ) -> Result<(Vec<usize>, C), GraphError>
+    where
+        C: Weight,
+    {
+        let order = self
+            .topological_sort()
+            .ok_or(GraphError::GraphContainsCycle)?;
+
+        let num_nodes = self.number_nodes();
+        let durations: Vec<C> = (0..num_nodes).map(durations).collect();
+        let mut finish_times = durations.clone();
+        let mut predecessors = vec![None; num_nodes];
+        for &u in &order {
+            //  Access CSR arrays directly.
+            let start = self.forward_edges.offsets[u];
+            let end = self.forward_edges.offsets[u + 1];
+            for &v in &self.forward_edges.targets[start..end] {
+                let candidate = finish_times[u] + durations[v];
+                if candidate > finish_times[v] {
+                    finish_times[v] = candidate;
+                    predecessors[v] = Some(u);
+                }
+            }
+        }
+
+        Ok(trace_longest(&order, &finish_times, &predecessors))
+    }
+}
+
+/// Rebuilds the longest path from the node with the largest distance.
+///
+/// Ties are broken in favor of the node that comes first in topological order.
+fn trace_longest<C: Weight>(
+    order: &[usize],
+    distances: &[C],
+    predecessors: &[Option<usize>],
+) -> (Vec<usize>, C) {
+    let Some(&first) = order.first() else {
+        return (Vec::new(), C::zero());
+    };
+
+    let end = order.iter().fold(first, |best, &node| {
+        if distances[node] > distances[best] {
+            node
+        } else {
+            best
+        }
+    });
+
+    let mut path = vec![end];
+    let mut current = end;
+    while let Some(predecessor) = predecessors[current] {
+        path.push(predecessor);
+        current = predecessor;
+    }
+    path.reverse();
+    (path, distances[end])
+}
@@ -0,0 +1,427 @@
+use crate::types::graph_csm::graph_csm_cycles::ElementaryCycles;
+use crate::types::graph_csm::graph_csm_dominators::compute_dominators;
+use crate::{
+    CsmGraph, DominatorTree, DynamicGraph, Freezable, GraphError, GraphView,
+    StructuralGraphAlgorithms,
+};
+
+impl<N, W> StructuralGraphAlgorithms<N, W> for CsmGraph<N, W>
+where
+    W: Default,
+{
+    /// Computes the strongly connected components using an iterative version of
+    /// Tarjan's algorithm.
+    ///
+    /// Like `find_cycle`, the DFS keeps an explicit stack of `(node, edge cursor)`
+    /// frames, so it cannot overflow the call stack on deep graphs. Tarjan's
+    /// algorithm emits components in reverse topological order, so ids are assigned
+    /// from the back to make them follow the topological order.
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** O(V + E), as each node and edge is visited exactly once.
+    /// - **Space Complexity:** O(V) for the DFS bookkeeping and the component stack.
+    fn strongly_connected_components(&self) -> (Vec<usize>, usize) {
+        const UNVISITED: usize = usize::MAX;
+
+        let num_nodes = self.number_nodes();
+        let mut discovery = vec![UNVISITED; num_nodes];
+        let mut low_link = vec![0; num_nodes];
+        let mut on_stack = vec![false; num_nodes];
+        let mut component_stack = Vec::new();
+        let mut emitted = vec![UNVISITED; num_nodes];
+        let mut num_emitted = 0;
+        let mut counter = 0;
+
+        for root in 0..num_nodes {
+            if discovery[root] != UNVISITED {
+                continue;
+            }
+
+            // Each frame holds a node and the position of its next unexplored edge.
+            let mut stack = vec![(root, self.forward_edges.offsets[root])];
+            discovery[root] = counter;
+            low_link[root] = counter;
+            counter += 1;
+            component_stack.push(root);
+            on_stack[root] = true;
+
+            while let Some(&mut (u, ref mut cursor)) = stack.last_mut() {
+                if *cursor < self.forward_edges.offsets[u + 1] {
+                    let v = self.forward_edges.targets[*cursor];
+                    *cursor += 1;
+
+                    if discovery[v] == UNVISITED {
+                        discovery[v] = counter;
+                        low_link[v] = counter;
+                        counter += 1;
+                        component_stack.push(v);
+                        on_stack[v] = true;
+                        stack.push((v, self.forward_edges.offsets[v]));
+                    } else if on_stack[v] {
+                        low_link[u] = low_link[u].min(discovery[v]);
+                    }
+                } else {
+                    // All edges of `u` are explored.
+                    stack.pop();
+                    if let Some(&(parent, _)) = stack.last() {
+                        low_link[parent] = low_link[parent].min(low_link[u]);
+                    }
+
+                    // `u` is the root of an SCC: pop the whole component.
+                    if low_link[u] == discovery[u] {
+                        while let Some(w) = component_stack.pop() {
+                            on_stack[w] = false;
+                            emitted[w] = num_emitted;
+                            if w == u {
+                                break;
+                            }
+                        }
+                        num_emitted += 1;
+                    }
+                }
+            }
+        }
+
+        // Reverse the emission order to obtain topologically ordered ids.
+        let components = emitted.into_iter().map(|c| num_emitted - 1 - c).collect();
+        (components, num_emitted)
+    }
+
+    /// Computes the weakly connected components using a union-find (disjoint set) structure.
+    ///
+    /// Every edge merges the sets of its two endpoints; the smaller root always
+    /// becomes the parent, so the root of each set is its smallest node. Finds use
+    /// path halving to keep the trees shallow.
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** O((V + E) log V) in the worst case, and close to O(V + E) in practice.
+    /// - **Space Complexity:** O(V) for the parent array and the labels.
+    fn weakly_connected_components(&self) -> (Vec<usize>, usize) {
+        const UNLABELED: usize = usize::MAX;
+
+        fn find(parent: &mut [usize], mut x: usize) -> usize {
+            while parent[x] != x {
+                parent[x] = parent[parent[x]]; // Path halving
+                x = parent[x];
+            }
+            x
+        }
+
+        let num_nodes = self.number_nodes();
+        let mut parent: Vec<usize> = (0..num_nodes).collect();
+
+        for u in 0..num_nodes {
+            //  Access CSR arrays directly.
+            let start = self.forward_edges.offsets[u];
+            let end = self.forward_edges.offsets[u + 1];
+            for &v in &self.forward_edges.targets[start..end] {
+                let root_u = find(&mut parent, u);
+                let root_v = find(&mut parent, v);
+                if root_u != root_v {
+                    parent[root_u.max(root_v)] = root_u.min(root_v);
+                }
+            }
+        }
+
+        // Roots are the smallest members, so each component is labeled when its root is reached.
+        let mut labels = vec![UNLABELED; num_nodes];
+        let mut count = 0;
+        let mut components = Vec::with_capacity(num_nodes);
+        for u in 0..num_nodes {
+            let root = find(&mut parent, u);
+            if labels[root] == UNLABELED {
+                labels[root] = count;
+                count += 1;
+            }
+            components.push(labels[root]);
+        }
+
+        (components, count)
+    }
+
+    /// Enumerates the elementary cycles with an iterative, resumable version of
+    /// Johnson's algorithm.
+    ///
+    /// The adjacency is first restricted to edges inside an SCC, since no cycle can
+    /// leave its component. For each start node `s`, a DFS over the nodes `>= s` reports
+    /// every path that closes back to `s`. Johnson's blocking keeps the DFS from
+    /// re-entering nodes that cannot currently reach `s`. The DFS state is kept in the
+    /// iterator, so each call to `next` only runs until the next cycle is found.
+    ///
+    /// With `max_length` set, the blocking lists are not used, because a node that fails
+    /// to close a cycle within the cap may still close one along a shorter path; the
+    /// search is then a depth-limited DFS.
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** O((V + E) * (C + 1)) for C cycles without a length cap.
+    /// - **Space Complexity:** O(V + E) for the restricted adjacency and blocking lists.
+    fn cycles(
+        &self,
+        max_length: Option<usize>,
+        max_cycles: Option<usize>,
+    ) -> impl Iterator<Item = Vec<usize>> + '_ {
+        ElementaryCycles::new(self, max_length, max_cycles)
+    }
+
+    /// Finds the articulation points with Tarjan's low-link DFS over both edge directions.
+    ///
+    /// See `cut_structure` for the algorithm.
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** O(V + E).
+    /// - **Space Complexity:** O(V) for the DFS state.
+    fn articulation_points(&self) -> Vec<usize> {
+        let (is_articulation, _) = cut_structure(self);
+        is_articulation
+            .iter()
+            .enumerate()
+            .filter(|&(_, &is_cut)| is_cut)
+            .map(|(node, _)| node)
+            .collect()
+    }
+
+    /// Finds the bridges with Tarjan's low-link DFS over both edge directions.
+    ///
+    /// See `cut_structure` for the algorithm.
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** O(V + E + B log B) for B bridges, including the final sort.
+    /// - **Space Complexity:** O(V) for the DFS state.
+    fn bridges(&self) -> Vec<(usize, usize)> {
+        let (_, mut bridges) = cut_structure(self);
+        bridges.sort_unstable();
+        bridges
+    }
+
+    /// Builds the condensation DAG from the SCC labels.
+    ///
+    /// Edges inside a component are dropped and parallel edges between the same
+    /// pair of components are merged into one edge whose weight is their count.
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** O(V + E) plus the final freeze of the condensed graph.
+    /// - **Space Complexity:** O(V + E) for the condensed adjacency lists.
+    fn condensation(&self) -> CsmGraph<Vec<usize>, usize> {
+        let (components, count) = self.strongly_connected_components();
+
+        let mut members: Vec<Vec<usize>> = vec![Vec::new(); count];
+        for (node, &component) in components.iter().enumerate() {
+            members[component].push(node);
+        }
+
+        let mut edge_counts = vec![0; count];
+        let mut touched = Vec::new();
+        let mut edges: Vec<Vec<(usize, usize)>> = Vec::with_capacity(count);
+
+        for member_list in &members {
+            let source = components[member_list[0]];
+            for &u in member_list {
+                //  Access CSR arrays directly.
+                let start = self.forward_edges.offsets[u];
+                let end = self.forward_edges.offsets[u + 1];
+                for &v in &self.forward_edges.targets[start..end] {
+                    let target = components[v];
+                    if target != source {
+                        if edge_counts[target] == 0 {
+                            touched.push(target);
+                        }
+                        edge_counts[target] += 1;
+                    }
+                }
+            }
+
+            edges.push(touched.iter().map(|&t| (t, edge_counts[t])).collect());
+            for &t in &touched {
+                edge_counts[t] = 0;
+            }
+            touched.clear();
+        }
+
+        let nodes = members.into_iter().map(Some).collect();
+        DynamicGraph::from_parts(nodes, edges, None).freeze()
+    }
+
+    /// Computes the dominator tree from the designated root node.
+    ///
+    /// See `dominator_tree_from` for the algorithm.
+    fn dominator_tree(&self) -> Result<DominatorTree, GraphError> {
+        let root = self.root_index.ok_or(GraphError::RootNotFound)?;
+        self.dominator_tree_from(root)
+    }
+
+    /// Computes the dominator tree with the Cooper-Harvey-Kennedy algorithm.
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** O((V + E) * D) in the worst case, for D passes until the
+    ///   dominators converge. D is small for typical graphs.
+    /// - **Space Complexity:** O(V) for the postorder numbering and dominators.
+    fn dominator_tree_from(&self, root: usize) -> Result<DominatorTree, GraphError> {
+        if !self.contains_node(root) {
+            return Err(GraphError::NodeNotFound(root));
+        }
+        Ok(compute_dominators(
+            root,
+            &self.forward_edges,
+            &self.backward_edges,
+        ))
+    }
+
+    /// Computes the post-dominator tree by running the dominator algorithm on the
+    /// reversed graph, i.e., with the roles of `forward_edges` and `backward_edges`
+    /// swapped.
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** O((V + E) * D), as for `dominator_tree_from`.
+    /// - **Space Complexity:** O(V).
+    fn post_dominator_tree(&self, exit: usize) -> Result<DominatorTree, GraphError> {
+        if !self.contains_node(exit) {
+            return Err(GraphError::NodeNotFound(exit));
+        }
+        Ok(compute_dominators(
+            exit,
+            &self.backward_edges,
+            &self.forward_edges,
+        ))
+    }
+}
+
+// Marks the missing parent of a DFS root and undiscovered nodes.
+const NONE: usize = usize::MAX;
+
+// A frame of the iterative DFS in `cut_structure`.
+struct CutFrame {
+    node: usize,
+    parent: usize,
+    // Position in the merged neighbor list: forward edges first, then backward edges.
+    cursor: usize,
+    // Whether the edge to the parent has already been skipped once.
+    parent_skipped: bool,
+    // Whether the tree edge from the parent is a forward edge `parent -> node`.
+    via_forward: bool,
+}
+
+/// Computes the articulation flags and the (unsorted) bridges of the undirected graph.
+///
+/// This is Tarjan's low-link algorithm with an explicit stack, so it is robust against
+/// stack overflows. The neighbors of a node are its `forward_edges` followed by its
+/// `backward_edges`. `low[u]` is the smallest discovery time reachable from the DFS
+/// subtree of `u` with at most one back edge. For a tree edge `p -> u`:
+///
+/// - `low[u] >= disc[p]` means the subtree of `u` cannot bypass `p`, so a non-root `p`
+///   is an articulation point. A DFS root is one if it has more than one child.
+/// - `low[u] > disc[p]` means the subtree cannot reach `p` without the edge, so it is
+///   a bridge.
+///
+/// Only one edge to the parent is skipped, so a parallel or reverse edge between the
+/// same nodes counts as a back edge and prevents a bridge.
+fn cut_structure<N, W>(graph: &CsmGraph<N, W>) -> (Vec<bool>, Vec<(usize, usize)>)
+where
+    W: Default,
+{
+    let num_nodes = graph.number_nodes();
+    let forward = &graph.forward_edges;
+    let backward = &graph.backward_edges;
+
+    let forward_degree = |u: usize| forward.offsets[u + 1] - forward.offsets[u];
+    let degree = |u: usize| forward_degree(u) + backward.offsets[u + 1] - backward.offsets[u];
+    // Returns the neighbor at a merged position and whether it is a forward edge.
+    let neighbor = |u: usize, position: usize| {
+        if position < forward_degree(u) {
+            (forward.targets[forward.offsets[u] + position], true)
+        } else {
+            let position = position - forward_degree(u);
+            (backward.targets[backward.offsets[u] + position], false)
+        }
+    };
+
+    let mut discovery = vec![NONE; num_nodes];
+    let mut low = vec![NONE; num_nodes];
+    let mut is_articulation = vec![false; num_nodes];
+    let mut bridges = Vec::new();
+    let mut time = 0;
+    let mut stack: Vec<CutFrame> = Vec::new();
+
+    for root in 0..num_nodes {
+        if discovery[root] != NONE {
+            continue;
+        }
+
+        discovery[root] = time;
+        low[root] = time;
+        time += 1;
+        let mut root_children = 0;
+        stack.push(CutFrame {
+            node: root,
+            parent: NONE,
+            cursor: 0,
+            parent_skipped: false,
+            via_forward: false,
+        });
+
+        while let Some(frame) = stack.last_mut() {
+            let u = frame.node;
+            if frame.cursor < degree(u) {
+                let (v, is_forward) = neighbor(u, frame.cursor);
+                frame.cursor += 1;
+
+                if v == frame.parent && !frame.parent_skipped {
+                    frame.parent_skipped = true;
+                    continue;
+                }
+
+                if discovery[v] == NONE {
+                    // --- Tree Edge ---
+                    discovery[v] = time;
+                    low[v] = time;
+                    time += 1;
+                    if u == root {
+                        root_children += 1;
+                    }
+                    stack.push(CutFrame {
+                        node: v,
+                        parent: u,
+                        cursor: 0,
+                        parent_skipped: false,
+                        via_forward: is_forward,
+                    });
+                } else {
+                    // --- Back Edge ---
+                    low[u] = low[u].min(discovery[v]);
+                }
+            } else {
+                // All neighbors of `u` are explored; report to the parent.
+                let frame = stack.pop().unwrap();
+                let parent = frame.parent;
+                if parent == NONE {
+                    continue;
+                }
+
+                low[parent] = low[parent].min(low[u]);
+                if low[u] >= discovery[parent] && parent != root {
+                    is_articulation[parent] = true;
+                }
+                if low[u] > discovery[parent] {
+                    bridges.push(if frame.via_forward {
+                        (parent, u)
+                    } else {
+                        (u, parent)
+                    });
+                }
+            }
+        }
+
+        if root_children > 1 {
+            is_articulation[root] = true;
+        }
+    }
+
+    (is_articulation, bridges)
+}
@@ -0,0 +1,335 @@
+use crate::traits::graph_algo_weighted::ShortestPathTree;
+use crate::{CsmGraph, GraphError, GraphView, Weight, WeightedGraphAlgorithms};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+/// Private priority queue entry that orders by *smallest* weight first.
+///
+/// `BinaryHeap` is a max-heap, so the comparison is reversed. Ties are broken by
+/// node index to keep the exploration order deterministic. Incomparable weights
+/// (e.g. NaN) are treated as equal.
+#[derive(Clone, Copy, Debug)]
+struct MinScored<W>(W, usize);
+
+impl<W: PartialOrd> PartialEq for MinScored<W> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<W: PartialOrd> Eq for MinScored<W> {}
+
+impl<W: PartialOrd> PartialOrd for MinScored<W> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<W: PartialOrd> Ord for MinScored<W> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .0
+            .partial_cmp(&self.0)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| other.1.cmp(&self.1))
+    }
+}
+
+impl<N, W> WeightedGraphAlgorithms<N, W> for CsmGraph<N, W>
+where
+    W: Default + Weight,
+{
+    /// Finds the cheapest path from a start to a stop index using Dijkstra's algorithm.
+    ///
+    /// The search walks the forward CSR arrays directly and reads each edge weight
+    /// from the parallel `weights` vector. It stops as soon as the stop node is
+    /// settled, so it usually explores only part of the graph.
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** O((V + E) log V) with a binary heap.
+    /// - **Space Complexity:** O(V) for distances, predecessors and the heap.
+    fn shortest_path_weighted(
+        &self,
+        start_index: usize,
+        stop_index: usize,
+    ) -> Option<(Vec<usize>, W)> {
+        if !self.contains_node(start_index) || !self.contains_node(stop_index) {
+            return None;
+        }
+        if start_index == stop_index {
+            return Some((vec![start_index], W::zero()));
+        }
+
+        let (distances, predecessors) = self.dijkstra(start_index, Some(stop_index));
+        let cost = distances[stop_index]?;
+
+        // Reconstruct path by walking backwards from the stop index.
+        let mut path = Vec::new();
+        let mut current = Some(stop_index);
+        while let Some(curr_index) = current {
+            path.push(curr_index);
+            current = predecessors[curr_index];
+        }
+        path.reverse();
+        Some((path, cost))
+    }
+
+    /// Computes single-source cheapest distances using Dijkstra's algorithm.
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** O((V + E) log V) with a binary heap.
+    /// - **Space Complexity:** O(V) for distances, predecessors and the heap.
+    fn dijkstra_all(&self, start_index: usize) -> Result<Vec<Option<W>>, GraphError> {
+        if !self.contains_node(start_index) {
+            return Err(GraphError::NodeNotFound(start_index));
+        }
+
+        let (distances, _) = self.dijkstra(start_index, None);
+        Ok(distances)
+    }
+
+    /// Finds the cheapest path from a start to a stop index using A* search.
+    ///
+    /// Nodes are expanded in order of `g + h`, where `g` is the cost from the start
+    /// and `h` the heuristic estimate to the stop node. The search walks the forward
+    /// CSR arrays directly. A node is re-opened when a cheaper path to it is found
+    /// later, so the result is optimal for any admissible heuristic, not only for
+    /// consistent ones.
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** O((V + E) log V) for a consistent heuristic; the actual
+    ///   number of explored nodes depends on the heuristic's quality.
+    /// - **Space Complexity:** O(V) for costs, predecessors and the heap.
+    fn astar(
+        &self,
+        start_index: usize,
+        stop_index: usize,
+        heuristic: impl Fn(usize) -> W,
+    ) -> Option<(Vec<usize>, W)> {
+        if !self.contains_node(start_index) || !self.contains_node(stop_index) {
+            return None;
+        }
+        if start_index == stop_index {
+            return Some((vec![start_index], W::zero()));
+        }
+
+        let num_nodes = self.number_nodes();
+        let mut costs: Vec<Option<W>> = vec![None; num_nodes];
+        let mut predecessors = vec![None; num_nodes];
+        let mut closed = vec![false; num_nodes];
+        let mut heap = BinaryHeap::new();
+
+        costs[start_index] = Some(W::zero());
+        heap.push(MinScored(heuristic(start_index), start_index));
+
+        let mut found = false;
+        while let Some(MinScored(_, u)) = heap.pop() {
+            if u == stop_index {
+                found = true;
+                break;
+            }
+            // Skip stale heap entries for nodes that were already expanded.
+            if closed[u] {
+                continue;
+            }
+            closed[u] = true;
+
+            // The unwrap is safe because every node in the heap has a known cost.
+            let cost = costs[u].unwrap();
+
+            //  Access CSR arrays directly.
+            let start = self.forward_edges.offsets[u];
+            let end = self.forward_edges.offsets[u + 1];
+            for i in start..end {
+                let v = self.forward_edges.targets[i];
+                let next_cost = cost + self.forward_edges.weights[i];
+                if costs[v].is_none_or(|current| next_cost < current) {
+                    costs[v] = Some(next_cost);
+                    predecessors[v] = Some(u);
+                    closed[v] = false; // Re-open for inconsistent heuristics.
+                    heap.push(MinScored(next_cost + heuristic(v), v));
+                }
+            }
+        }
+
+        if !found {
+            return None;
+        }
+
+        // Reconstruct path by walking backwards from the stop index.
+        let mut path = Vec::new();
+        let mut current = Some(stop_index);
+        while let Some(curr_index) = current {
+            path.push(curr_index);
+            current = predecessors[curr_index];
+        }
+        path.reverse();
+        costs[stop_index].map(|cost| (path, cost))
+    }
+
+    /// Computes single-source cheapest distances using the Bellman-Ford algorithm.
+    ///
+    /// Every round relaxes all edges by scanning the forward CSR arrays in order.
+    /// The loop stops early once a round changes nothing, so graphs without long
+    /// shortest paths finish in far fewer than V rounds.
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** O(V * E) in the worst case.
+    /// - **Space Complexity:** O(V) for distances and predecessors.
+    fn bellman_ford(&self, start_index: usize) -> Result<ShortestPathTree<W>, GraphError> {
+        self.relax_from(start_index)?
+            .map_err(|cycle| GraphError::GraphContainsNegativeCycle(cycle[0]))
+    }
+
+    /// Finds a negative-weight cycle reachable from the start index using Bellman-Ford.
+    ///
+    /// This runs exactly the relaxation of `bellman_ford`, so the returned cycle
+    /// contains the node reported by its `GraphContainsNegativeCycle` error.
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** O(V * E) in the worst case.
+    /// - **Space Complexity:** O(V) for distances and predecessors.
+    fn negative_cycle_from(&self, start_index: usize) -> Result<Option<Vec<usize>>, GraphError> {
+        Ok(self.relax_from(start_index)?.err())
+    }
+
+    /// Finds a negative-weight cycle anywhere in the graph using Bellman-Ford.
+    ///
+    /// All nodes start at distance zero, which is equivalent to adding a virtual
+    /// source with a zero-weight edge to every node. This way a negative cycle is
+    /// found in any of the graph's disconnected components.
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** O(V * E) in the worst case.
+    /// - **Space Complexity:** O(V) for distances and predecessors.
+    fn find_negative_cycle(&self) -> Option<Vec<usize>> {
+        let distances = vec![Some(W::zero()); self.number_nodes()];
+        self.relax_all(distances).err()
+    }
+}
+
+impl<N, W> CsmGraph<N, W>
+where
+    W: Default + Weight,
+{
+    /// Runs the Bellman-Ford core from a single start index.
+    fn relax_from(
+        &self,
+        start_index: usize,
+    ) -> Result<Result<ShortestPathTree<W>, Vec<usize>>, GraphError> {
+        if !self.contains_node(start_index) {
+            return Err(GraphError::NodeNotFound(start_index));
+        }
+
+        let mut distances = vec![None; self.number_nodes()];
+        distances[start_index] = Some(W::zero());
+        Ok(self.relax_all(distances))
+    }
+
+    /// Shared Bellman-Ford core. Relaxes all edges starting from the given initial
+    /// distances until they converge.
+    ///
+    /// Returns the final distances and predecessors, or the negative cycle found
+    /// as a closed path `[v, ..., v]` if the distances do not converge within V rounds.
+    fn relax_all(&self, mut distances: Vec<Option<W>>) -> Result<ShortestPathTree<W>, Vec<usize>> {
+        let num_nodes = self.number_nodes();
+        let mut predecessors = vec![None; num_nodes];
+
+        // After V - 1 rounds all shortest paths have converged, so a change in
+        // round V proves the existence of a negative cycle.
+        let mut last_relaxed = None;
+        for _ in 0..num_nodes {
+            last_relaxed = None;
+            for u in 0..num_nodes {
+                let Some(cost) = distances[u] else {
+                    continue;
+                };
+
+                //  Access CSR arrays directly.
+                let start = self.forward_edges.offsets[u];
+                let end = self.forward_edges.offsets[u + 1];
+                for i in start..end {
+                    let v = self.forward_edges.targets[i];
+                    let next_cost = cost + self.forward_edges.weights[i];
+                    if distances[v].is_none_or(|current| next_cost < current) {
+                        distances[v] = Some(next_cost);
+                        predecessors[v] = Some(u);
+                        last_relaxed = Some(v);
+                    }
+                }
+            }
+
+            if last_relaxed.is_none() {
+                return Ok((distances, predecessors));
+            }
+        }
+
+        // --- Negative Cycle Found: Reconstruct the Path ---
+        // Walking back V steps from the last relaxed node guarantees that we end
+        // up on the cycle itself rather than on a path leading into it.
+        // Without any relaxation, the graph is empty and there is nothing to report.
+        let Some(mut on_cycle) = last_relaxed else {
+            return Ok((distances, predecessors));
+        };
+        // The unwraps are safe because every relaxed node has a predecessor.
+        for _ in 0..num_nodes {
+            on_cycle = predecessors[on_cycle].unwrap();
+        }
+
+        let mut path = vec![on_cycle];
+        let mut current = predecessors[on_cycle].unwrap();
+        while current != on_cycle {
+            path.push(current);
+            current = predecessors[current].unwrap();
+        }
+        path.reverse();
+        path.push(path[0]); // Make the cycle explicit: [v, ..., u, v]
+        Err(path)
+    }
+
+    /// Shared Dijkstra core. Returns the distances and predecessors of all settled
+    /// nodes, stopping early once `stop_index` is settled if one is given.
+    fn dijkstra(&self, start_index: usize, stop_index: Option<usize>) -> ShortestPathTree<W> {
+        let num_nodes = self.number_nodes();
+        let mut distances: Vec<Option<W>> = vec![None; num_nodes];
+        let mut predecessors = vec![None; num_nodes];
+        let mut settled = vec![false; num_nodes];
+        let mut heap = BinaryHeap::new();
+
+        distances[start_index] = Some(W::zero());
+        heap.push(MinScored(W::zero(), start_index));
+
+        while let Some(MinScored(cost, u)) = heap.pop() {
+            // Skip stale heap entries for nodes that were already settled.
+            if settled[u] {
+                continue;
+            }
+            settled[u] = true;
+
+            if Some(u) == stop_index {
+                break;
+            }
+
+            //  Access CSR arrays directly.
+            let start = self.forward_edges.offsets[u];
+            let end = self.forward_edges.offsets[u + 1];
+            for i in start..end {
+                let v = self.forward_edges.targets[i];
+                let next_cost = cost + self.forward_edges.weights[i];
+                if distances[v].is_none_or(|current| next_cost < current) {
+                    distances[v] = Some(next_cost);
+                    predecessors[v] = Some(u);
+                    heap.push(MinScored(next_cost, v));
+                }
+            }
+        }
+
+        (distances, predecessors)
+    }
+}
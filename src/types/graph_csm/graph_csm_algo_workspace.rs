@@ -0,0 +1,168 @@
+use crate::types::traversal_workspace::NO_PREDECESSOR;
+use crate::{CsmGraph, GraphView, TraversalWorkspace};
+
+/// Allocation-free variants of the traversal algorithms.
+///
+/// Each method behaves exactly like its counterpart in `GraphAlgorithms`, but uses the
+/// buffers of a caller-provided `TraversalWorkspace` instead of allocating its own.
+/// The `GraphAlgorithms` methods are thin wrappers that pass a fresh workspace.
+impl<N, W> CsmGraph<N, W>
+where
+    W: Default,
+{
+    /// Finds a cycle in the graph using an iterative DFS and the given workspace.
+    ///
+    /// See `GraphAlgorithms::find_cycle` for the format of the returned cycle.
+    pub fn find_cycle_with_workspace(
+        &self,
+        workspace: &mut TraversalWorkspace,
+    ) -> Option<Vec<usize>> {
+        let num_nodes = self.number_nodes();
+        workspace.begin(num_nodes);
+
+        // A node is "in progress" while it is visited but not yet finished.
+        for i in 0..num_nodes {
+            if workspace.is_visited(i) {
+                continue;
+            }
+
+            workspace.visit(i, NO_PREDECESSOR);
+            workspace.stack.push((i, self.forward_edges.offsets[i]));
+
+            while let Some(&mut (u, ref mut cursor)) = workspace.stack.last_mut() {
+                //  Access CSR arrays directly.
+                if *cursor < self.forward_edges.offsets[u + 1] {
+                    let v = self.forward_edges.targets[*cursor];
+                    *cursor += 1;
+
+                    if workspace.is_visited(v) && !workspace.is_finished(v) {
+                        // --- Cycle Found: Reconstruct the Path ---
+                        let mut path = vec![u];
+                        let mut current = u;
+
+                        while let Some(predecessor) = workspace.predecessor(current) {
+                            if current == v {
+                                break;
+                            }
+                            path.push(predecessor);
+                            current = predecessor;
+                        }
+                        path.reverse();
+                        path.push(v); // Make the cycle explicit: [v, ..., u, v]
+                        return Some(path);
+                    }
+
+                    if !workspace.is_visited(v) {
+                        workspace.visit(v, u);
+                        workspace.stack.push((v, self.forward_edges.offsets[v]));
+                    }
+                } else {
+                    // If all edges are explored, we are done with this node.
+                    workspace.finish(u);
+                    workspace.stack.pop();
+                }
+            }
+        }
+
+        None // No cycles found after checking all nodes.
+    }
+
+    /// Checks if a path exists from a start to a stop index using the given workspace.
+    pub fn is_reachable_with_workspace(
+        &self,
+        start_index: usize,
+        stop_index: usize,
+        workspace: &mut TraversalWorkspace,
+    ) -> bool {
+        self.shortest_path_len_with_workspace(start_index, stop_index, workspace)
+            .is_some()
+    }
+
+    /// Returns the length of the shortest path (in number of nodes) from a start to a
+    /// stop index, using a BFS over the given workspace.
+    pub fn shortest_path_len_with_workspace(
+        &self,
+        start_index: usize,
+        stop_index: usize,
+        workspace: &mut TraversalWorkspace,
+    ) -> Option<usize> {
+        if !self.contains_node(start_index) || !self.contains_node(stop_index) {
+            return None;
+        }
+        if start_index == stop_index {
+            return Some(1);
+        }
+
+        workspace.begin(self.number_nodes());
+        workspace.queue.push_back((start_index, 1)); // (node, path_length)
+        workspace.visit(start_index, NO_PREDECESSOR);
+
+        while let Some((current_node, current_len)) = workspace.queue.pop_front() {
+            //  Access CSR arrays directly.
+            let start = self.forward_edges.offsets[current_node];
+            let end = self.forward_edges.offsets[current_node + 1];
+            for &neighbor in &self.forward_edges.targets[start..end] {
+                if neighbor == stop_index {
+                    return Some(current_len + 1);
+                }
+                if !workspace.is_visited(neighbor) {
+                    workspace.visit(neighbor, current_node);
+                    workspace.queue.push_back((neighbor, current_len + 1));
+                }
+            }
+        }
+        None
+    }
+
+    /// Finds the complete shortest path from a start to a stop index, using a BFS over
+    /// the given workspace.
+    pub fn shortest_path_with_workspace(
+        &self,
+        start_index: usize,
+        stop_index: usize,
+        workspace: &mut TraversalWorkspace,
+    ) -> Option<Vec<usize>> {
+        if !self.contains_node(start_index) || !self.contains_node(stop_index) {
+            return None;
+        }
+        if start_index == stop_index {
+            return Some(vec![start_index]);
+        }
+
+        workspace.begin(self.number_nodes());
+        workspace.queue.push_back((start_index, 1)); // (node, path_length)
+        workspace.visit(start_index, NO_PREDECESSOR);
+
+        let mut found = false;
+        'bfs_loop: while let Some((current_node, current_len)) = workspace.queue.pop_front() {
+            //  Access CSR arrays directly.
+            let start = self.forward_edges.offsets[current_node];
+            let end = self.forward_edges.offsets[current_node + 1];
+            for &neighbor in &self.forward_edges.targets[start..end] {
+                if !workspace.is_visited(neighbor) {
+                    workspace.visit(neighbor, current_node);
+                    workspace.queue.push_back((neighbor, current_len + 1));
+
+                    if neighbor == stop_index {
+                        found = true;
+                        break 'bfs_loop;
+                    }
+                }
+            }
+        }
+
+        if !found {
+            return None;
+        }
+
+        // Reconstruct path by walking backwards from the stop index.
+        let mut path = Vec::new();
+        let mut current = Some(stop_index);
+        while let Some(curr_index) = current {
+            path.push(curr_index);
+            current = workspace.predecessor(curr_index);
+        }
+        path.reverse();
+        Some(path)
+    }
+}
@@ -0,0 +1,184 @@
+use crate::{CsmGraph, GraphView, StructuralGraphAlgorithms};
+
+/// A lazy iterator over the elementary cycles of a `CsmGraph` (Johnson's algorithm).
+///
+/// The iterator owns a compact copy of the adjacency that keeps only edges inside
+/// an SCC, with parallel edges merged, so it does not borrow the graph. Cycles are
+/// found for one start node `s` at a time, considering only nodes `>= s`, so every
+/// cycle is reported exactly once, starting at its smallest node.
+pub(crate) struct ElementaryCycles {
+    // Deduplicated successors of every node, restricted to its own SCC.
+    adjacency: Vec<Vec<usize>>,
+    max_length: Option<usize>,
+    remaining: Option<usize>,
+
+    // The current start node. Equal to `adjacency.len()` once exhausted.
+    start: usize,
+    // The current path from `start` and the edge cursor of every node on it.
+    path: Vec<usize>,
+    cursors: Vec<usize>,
+    // Whether a cycle was found below each frame of the path.
+    found: Vec<bool>,
+    blocked: Vec<bool>,
+    // Johnson's B-lists: the nodes to unblock once a node gets unblocked.
+    blocked_by: Vec<Vec<usize>>,
+    // The nodes visited by the search from `start`, so only they need to be reset.
+    touched: Vec<usize>,
+    // The start node (plus one) whose search last touched each node.
+    touched_by: Vec<usize>,
+}
+
+impl ElementaryCycles {
+    pub(crate) fn new<N, W>(
+        graph: &CsmGraph<N, W>,
+        max_length: Option<usize>,
+        max_cycles: Option<usize>,
+    ) -> Self
+    where
+        W: Default,
+    {
+        let num_nodes = graph.number_nodes();
+        let (components, _) = graph.strongly_connected_components();
+
+        let adjacency = (0..num_nodes)
+            .map(|u| {
+                //  Access CSR arrays directly.
+                let start = graph.forward_edges.offsets[u];
+                let end = graph.forward_edges.offsets[u + 1];
+                let mut targets: Vec<usize> = graph.forward_edges.targets[start..end]
+                    .iter()
+                    .copied()
+                    .filter(|&v| components[v] == components[u])
+                    .collect();
+                targets.sort_unstable();
+                targets.dedup();
+                targets
+            })
+            .collect();
+
+        Self {
+            adjacency,
+            max_length,
+            remaining: max_cycles,
+            start: 0,
+            path: Vec::new(),
+            cursors: Vec::new(),
+            found: Vec::new(),
+            blocked: vec![false; num_nodes],
+            blocked_by: vec![Vec::new(); num_nodes],
+            touched: Vec::new(),
+            touched_by: vec![0; num_nodes],
+        }
+    }
+
+    /// Unblocks `u` and, transitively, every node waiting on it.
+    fn unblock(&mut self, u: usize) {
+        let mut stack = vec![u];
+        while let Some(w) = stack.pop() {
+            if self.blocked[w] {
+                self.blocked[w] = false;
+                stack.append(&mut self.blocked_by[w]);
+            }
+        }
+    }
+
+    fn push(&mut self, u: usize) {
+        self.path.push(u);
+        self.cursors.push(0);
+        self.found.push(false);
+        self.blocked[u] = true;
+        if self.touched_by[u] != self.start + 1 {
+            self.touched_by[u] = self.start + 1;
+            self.touched.push(u);
+        }
+    }
+
+    /// Continues the DFS from the current state and returns the next cycle, if any.
+    fn next_cycle(&mut self) -> Option<Vec<usize>> {
+        let num_nodes = self.adjacency.len();
+
+        while self.start < num_nodes {
+            let s = self.start;
+            if self.path.is_empty() {
+                // Only start a search if the start node lies on a cycle at all.
+                if self.adjacency[s].iter().any(|&v| v >= s) {
+                    self.push(s);
+                } else {
+                    self.start += 1;
+                    continue;
+                }
+            }
+
+            while let Some(&u) = self.path.last() {
+                let depth = self.path.len() - 1;
+                let cursor = self.cursors[depth];
+
+                if let Some(&w) = self.adjacency[u].get(cursor) {
+                    self.cursors[depth] += 1;
+                    if w < s {
+                        continue;
+                    }
+                    // A cycle through the current path has `path.len()` edges.
+                    if w == s && self.max_length.is_none_or(|max| self.path.len() <= max) {
+                        // --- Cycle Found: the path closes back to the start ---
+                        self.found[depth] = true;
+                        let mut cycle = self.path.clone();
+                        cycle.push(s); // Make the cycle explicit: [s, ..., u, s]
+                        return Some(cycle);
+                    }
+                    if w == s {
+                        continue;
+                    }
+                    let within_length = self.max_length.is_none_or(|max| self.path.len() < max);
+                    if !self.blocked[w] && within_length {
+                        self.push(w);
+                    }
+                } else {
+                    // All edges of `u` are explored.
+                    self.path.pop();
+                    self.cursors.pop();
+                    let found = self.found.pop().unwrap_or(false);
+
+                    if found || self.max_length.is_some() {
+                        // With a length cap, a node that failed to close a cycle may still
+                        // close one along a shorter path, so it must always be unblocked.
+                        self.unblock(u);
+                    } else {
+                        for &w in &self.adjacency[u] {
+                            if w > s && !self.blocked_by[w].contains(&u) {
+                                self.blocked_by[w].push(u);
+                            }
+                        }
+                    }
+                    if let Some(parent_found) = self.found.last_mut() {
+                        *parent_found |= found;
+                    }
+                }
+            }
+
+            // The search from `s` is complete: reset the blocking state for the next start.
+            for u in self.touched.drain(..) {
+                self.blocked[u] = false;
+                self.blocked_by[u].clear();
+            }
+            self.start += 1;
+        }
+
+        None
+    }
+}
+
+impl Iterator for ElementaryCycles {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == Some(0) {
+            return None;
+        }
+        let cycle = self.next_cycle()?;
+        if let Some(remaining) = self.remaining.as_mut() {
+            *remaining -= 1;
+        }
+        Some(cycle)
+    }
+}
@@ -0,0 +1,89 @@
+use crate::DominatorTree;
+use crate::types::dominator_tree::UNREACHABLE;
+use crate::types::graph_csm::CsrAdjacency;
+
+/// Computes the dominator tree from `root` with the Cooper-Harvey-Kennedy algorithm.
+///
+/// The algorithm solves the dominator data-flow equations iteratively. Nodes are
+/// numbered in DFS postorder and then processed in reverse postorder, so that most
+/// predecessors are handled before their successors. The immediate dominator of a
+/// node is the intersection of the (already known) dominators of its predecessors,
+/// found by walking both dominator chains upwards until they meet. In practice, this
+/// converges in very few passes and beats Lengauer-Tarjan on all but huge graphs.
+///
+/// Passing the `backward_edges` as `successors` and the `forward_edges` as
+/// `predecessors` yields the post-dominator tree instead.
+pub(crate) fn compute_dominators<W>(
+    root: usize,
+    successors: &CsrAdjacency<W>,
+    predecessors: &CsrAdjacency<W>,
+) -> DominatorTree {
+    let num_nodes = successors.offsets.len() - 1;
+
+    // 1. Number the reachable nodes in DFS postorder with an iterative DFS.
+    let mut postorder_numbers = vec![UNREACHABLE; num_nodes];
+    let mut postorder = Vec::with_capacity(num_nodes);
+    let mut discovered = vec![false; num_nodes];
+    discovered[root] = true;
+    let mut stack = vec![(root, successors.offsets[root])];
+    while let Some((u, cursor)) = stack.last_mut() {
+        let u = *u;
+        if *cursor < successors.offsets[u + 1] {
+            let v = successors.targets[*cursor];
+            *cursor += 1;
+            if !discovered[v] {
+                discovered[v] = true;
+                stack.push((v, successors.offsets[v]));
+            }
+        } else {
+            stack.pop();
+            postorder_numbers[u] = postorder.len();
+            postorder.push(u);
+        }
+    }
+
+    // 2. Iterate to a fixed point in reverse postorder. The root dominates itself.
+    let mut idoms = vec![UNREACHABLE; num_nodes];
+    idoms[root] = root;
+
+    let intersect = |idoms: &[usize], mut a: usize, mut b: usize| {
+        while a != b {
+            while postorder_numbers[a] < postorder_numbers[b] {
+                a = idoms[a];
+            }
+            while postorder_numbers[b] < postorder_numbers[a] {
+                b = idoms[b];
+            }
+        }
+        a
+    };
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for &u in postorder.iter().rev().skip(1) {
+            let start = predecessors.offsets[u];
+            let end = predecessors.offsets[u + 1];
+
+            // Only predecessors with a known dominator take part; unreachable ones never do.
+            let mut new_idom = UNREACHABLE;
+            for &p in &predecessors.targets[start..end] {
+                if idoms[p] == UNREACHABLE {
+                    continue;
+                }
+                new_idom = if new_idom == UNREACHABLE {
+                    p
+                } else {
+                    intersect(&idoms, p, new_idom)
+                };
+            }
+
+            if idoms[u] != new_idom {
+                idoms[u] = new_idom;
+                changed = true;
+            }
+        }
+    }
+
+    DominatorTree::new(root, idoms)
+}
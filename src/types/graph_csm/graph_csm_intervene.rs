@@ -0,0 +1,92 @@
+use crate::types::graph_csm::CsrAdjacency;
+use crate::{CsmGraph, GraphView};
+
+impl<N, W> CsmGraph<N, W>
+where
+    W: Default,
+{
+    /// Performs graph surgery for the do-operator: returns a copy of the graph in which
+    /// all incoming edges of the intervened nodes are removed.
+    ///
+    /// Setting `do(X = x)` replaces the causal mechanism of `X` by a constant, so `X` no
+    /// longer depends on its parents. The mutilated graph is built directly from the CSR
+    /// arrays, without an `unfreeze` and `freeze` round trip. Nodes, node indices, the
+    /// root and all other edges, including their weights, stay unchanged.
+    ///
+    /// # Arguments
+    /// * `nodes`: The indices of the intervened nodes. Duplicates are allowed.
+    ///
+    /// # Panics
+    /// Panics if any of the nodes does not exist, like indexing out of bounds. Use
+    /// `contains_node` to validate indices that come from untrusted input.
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** O(V + E) for copying the CSR arrays.
+    /// - **Space Complexity:** O(V + E) for the new graph.
+    pub fn intervene(&self, nodes: &[usize]) -> CsmGraph<N, W>
+    where
+        N: Clone,
+        W: Clone,
+    {
+        let num_nodes = self.number_nodes();
+        let mut is_intervened = vec![false; num_nodes];
+        for &node in nodes {
+            assert!(
+                self.contains_node(node),
+                "Cannot intervene on node {node}: it does not exist."
+            );
+            is_intervened[node] = true;
+        }
+
+        // Forward edges: drop every edge that points to an intervened node.
+        let mut forward_edges = CsrAdjacency {
+            offsets: Vec::with_capacity(num_nodes + 1),
+            targets: Vec::with_capacity(self.forward_edges.targets.len()),
+            weights: Vec::with_capacity(self.forward_edges.weights.len()),
+        };
+        forward_edges.offsets.push(0);
+        for u in 0..num_nodes {
+            let start = self.forward_edges.offsets[u];
+            let end = self.forward_edges.offsets[u + 1];
+            for i in start..end {
+                let v = self.forward_edges.targets[i];
+                if !is_intervened[v] {
+                    forward_edges.targets.push(v);
+                    forward_edges
+                        .weights
+                        .push(self.forward_edges.weights[i].clone());
+                }
+            }
+            forward_edges.offsets.push(forward_edges.targets.len());
+        }
+
+        // Backward edges: an intervened node simply loses its whole predecessor list.
+        let mut backward_edges = CsrAdjacency {
+            offsets: Vec::with_capacity(num_nodes + 1),
+            targets: Vec::with_capacity(forward_edges.targets.len()),
+            weights: Vec::with_capacity(forward_edges.weights.len()),
+        };
+        backward_edges.offsets.push(0);
+        for (v, &intervened) in is_intervened.iter().enumerate() {
+            if !intervened {
+                let start = self.backward_edges.offsets[v];
+                let end = self.backward_edges.offsets[v + 1];
+                backward_edges
+                    .targets
+                    .extend_from_slice(&self.backward_edges.targets[start..end]);
+                backward_edges
+                    .weights
+                    .extend_from_slice(&self.backward_edges.weights[start..end]);
+            }
+            backward_edges.offsets.push(backward_edges.targets.len());
+        }
+
+        CsmGraph::construct(
+            self.nodes.clone(),
+            forward_edges,
+            backward_edges,
+            self.root_index,
+        )
+    }
+}
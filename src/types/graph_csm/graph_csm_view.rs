@@ -49,7 +49,7 @@ where
         // Choose the best algorithm based on the number of neighbors.
         if targets_slice.len() < BINARY_SEARCH_THRESHOLD {
             // For small lists, a linear scan over a simple `Vec<usize>` is extremely fast.
-            targets_slice.iter().any(|&target| target == b)
+            targets_slice.contains(&b)
         } else {
             // For larger lists, binary search is asymptotically faster.
             // This relies on the slice being pre-sorted by target index during `.freeze()`.
@@ -1,4 +1,12 @@
 mod graph_csm_algo;
+mod graph_csm_algo_causal;
+mod graph_csm_algo_dag;
+mod graph_csm_algo_structural;
+mod graph_csm_algo_weighted;
+mod graph_csm_algo_workspace;
+mod graph_csm_cycles;
+mod graph_csm_dominators;
+mod graph_csm_intervene;
 mod graph_csm_unfreeze;
 mod graph_csm_view;
 mod graph_traversal;
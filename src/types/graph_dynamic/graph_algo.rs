@@ -0,0 +1,50 @@
+use crate::{DynamicGraph, GraphAlgorithms, algo};
+
+// All algorithms work on the stable node indices of the `DynamicGraph`. Removed
+// ("tombstoned") nodes and all edges from or to them are ignored, because the
+// generic implementations in `algo` only see what `GraphTraversal` exposes. The
+// results match those of the frozen graph up to the re-indexing performed by `freeze`.
+impl<N, W> GraphAlgorithms<N, W> for DynamicGraph<N, W> {
+    /// Finds a cycle in the graph using an iterative Depth-First Search (DFS).
+    ///
+    /// This allows validating a graph while it evolves, without consuming it with
+    /// `freeze`. See `GraphAlgorithms::find_cycle` for the format of the returned cycle.
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** O(V + E), like the search on the frozen graph.
+    /// - **Space Complexity:** O(V + E) for the traversal state and buffered successors.
+    fn find_cycle(&self) -> Option<Vec<usize>> {
+        algo::find_cycle(self)
+    }
+
+    /// Checks if the graph contains any directed cycles.
+    fn has_cycle(&self) -> bool {
+        algo::has_cycle(self)
+    }
+
+    /// Computes a topological sort of the valid nodes using Kahn's algorithm.
+    ///
+    /// # Returns
+    /// - `Some(Vec<usize>)` with the stable indices of all valid nodes in a topological
+    ///   order if the graph is a DAG.
+    /// - `None` if the graph contains a cycle.
+    fn topological_sort(&self) -> Option<Vec<usize>> {
+        algo::topological_sort(self)
+    }
+
+    /// Checks if a path exists from a start to a stop index.
+    fn is_reachable(&self, start_index: usize, stop_index: usize) -> bool {
+        algo::is_reachable(self, start_index, stop_index)
+    }
+
+    /// Returns the length of the shortest path (in number of nodes) from a start to a stop index.
+    fn shortest_path_len(&self, start_index: usize, stop_index: usize) -> Option<usize> {
+        algo::shortest_path_len(self, start_index, stop_index)
+    }
+
+    /// Finds the complete shortest path from a start to a stop index using a BFS.
+    fn shortest_path(&self, start_index: usize, stop_index: usize) -> Option<Vec<usize>> {
+        algo::shortest_path(self, start_index, stop_index)
+    }
+}
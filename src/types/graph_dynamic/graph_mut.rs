@@ -10,12 +10,14 @@ impl<N, W> GraphMut<N, W> for DynamicGraph<N, W> {
         let index = self.nodes.len();
         self.nodes.push(Some(node));
 
-        if self.num_edges_per_node.is_some() {
-            let edge_capacity = self.num_edges_per_node.unwrap();
+        if let Some(edge_capacity) = self.num_edges_per_node {
             self.edges.push(Vec::with_capacity(edge_capacity)); // Add a corresponding edge list with edge_capacity
         } else {
             self.edges.push(Vec::default()); // Add a corresponding empty edge list
         };
+        if let Some(reverse_edges) = self.reverse_edges.as_mut() {
+            reverse_edges.push(Vec::new());
+        }
 
         index
     }
@@ -56,6 +58,12 @@ impl<N, W> GraphMut<N, W> for DynamicGraph<N, W> {
                 *node_slot = None; // "Tombstone" the node
                 // Clear its outgoing edges. Incoming edges will be handled during freeze.
                 if let Some(edges_list) = self.edges.get_mut(index) {
+                    // Keep the reverse index in sync with the cleared edges.
+                    if let Some(reverse_edges) = self.reverse_edges.as_mut() {
+                        for (target, _) in edges_list.iter() {
+                            remove_source(&mut reverse_edges[*target], index);
+                        }
+                    }
                     edges_list.clear();
                 }
                 // If this was the root, clear the root index
@@ -85,6 +93,9 @@ impl<N, W> GraphMut<N, W> for DynamicGraph<N, W> {
 
         // This check is safe because `contains_node` confirmed `a` is in bounds.
         self.edges[a].push((b, weight));
+        if let Some(reverse_edges) = self.reverse_edges.as_mut() {
+            reverse_edges[b].push(a);
+        }
         Ok(())
     }
 
@@ -106,6 +117,9 @@ impl<N, W> GraphMut<N, W> for DynamicGraph<N, W> {
         if let Some(pos) = self.edges[a].iter().position(|(target, _)| *target == b) {
             // Use `swap_remove` for O(1) removal (amortized), as edge order is not guaranteed.
             self.edges[a].swap_remove(pos);
+            if let Some(reverse_edges) = self.reverse_edges.as_mut() {
+                remove_source(&mut reverse_edges[b], a);
+            }
             Ok(())
         } else {
             Err(GraphError::EdgeNotFoundError {
@@ -128,5 +142,16 @@ impl<N, W> GraphMut<N, W> for DynamicGraph<N, W> {
         self.nodes.clear();
         self.edges.clear();
         self.root_index = None;
+        // An enabled reverse index stays enabled, but empty.
+        if let Some(reverse_edges) = self.reverse_edges.as_mut() {
+            reverse_edges.clear();
+        }
+    }
+}
+
+/// Removes one occurrence of `source` from a reverse adjacency list.
+fn remove_source(sources: &mut Vec<usize>, source: usize) {
+    if let Some(pos) = sources.iter().position(|&s| s == source) {
+        sources.swap_remove(pos);
     }
 }
@@ -0,0 +1,60 @@
+use crate::{DynamicGraph, GraphError, GraphTraversal, GraphView};
+
+impl<N, W> GraphTraversal<N, W> for DynamicGraph<N, W> {
+    /// Returns a non-allocating iterator over the direct successors (outgoing edges) of node `a`.
+    ///
+    /// Edges to removed ("tombstoned") nodes are skipped. The successors are yielded in
+    /// the order of the node's edge list, which is not sorted.
+    ///
+    /// # Errors
+    /// Returns `GraphError::NodeNotFound` if the node does not exist or has been removed.
+    fn outbound_edges(&self, a: usize) -> Result<impl Iterator<Item = usize> + '_, GraphError> {
+        if !self.contains_node(a) {
+            return Err(GraphError::NodeNotFound(a));
+        }
+
+        Ok(self.edges[a]
+            .iter()
+            .map(|(target, _)| *target)
+            .filter(|&target| self.contains_node(target)))
+    }
+
+    /// Returns a non-allocating iterator over the direct predecessors (incoming edges) of node `a`.
+    ///
+    /// Edges from removed ("tombstoned") nodes are skipped. If the reverse index is
+    /// enabled (see `DynamicGraph::enable_reverse_index`), this is O(in-degree).
+    /// Otherwise, it lazily scans all edges of the graph in O(V + E) and yields the
+    /// predecessors in increasing index order.
+    ///
+    /// # Errors
+    /// Returns `GraphError::NodeNotFound` if the node does not exist or has been removed.
+    fn inbound_edges(&self, a: usize) -> Result<impl Iterator<Item = usize> + '_, GraphError> {
+        if !self.contains_node(a) {
+            return Err(GraphError::NodeNotFound(a));
+        }
+
+        // Exactly one of the two sources is active, which avoids boxing the iterator.
+        let indexed = self
+            .reverse_edges
+            .as_ref()
+            .map(|reverse_edges| reverse_edges[a].iter().copied());
+
+        let scanned = self.reverse_edges.is_none().then(|| {
+            self.edges
+                .iter()
+                .enumerate()
+                .flat_map(move |(source, edge_list)| {
+                    edge_list
+                        .iter()
+                        .filter(move |(target, _)| *target == a)
+                        .map(move |_| source)
+                })
+        });
+
+        Ok(indexed
+            .into_iter()
+            .flatten()
+            .chain(scanned.into_iter().flatten())
+            .filter(|&source| self.contains_node(source)))
+    }
+}
@@ -31,11 +31,18 @@ impl<N, W> GraphView<N, W> for DynamicGraph<N, W> {
         self.nodes.iter().filter(|n| n.is_some()).count()
     }
 
+    /// Returns the number of node slots, including removed ("tombstoned") nodes.
+    ///
+    /// Node indices are stable, so valid indices can be larger than `number_nodes()`.
+    fn node_index_bound(&self) -> usize {
+        self.nodes.len()
+    }
+
     /// Checks if a directed edge exists from node `a` to node `b`.
     fn contains_edge(&self, a: usize, b: usize) -> bool {
         // We get the edge list for node `a`. If `a` is out of bounds or has been removed,
-        // `get` will return `None`, and the `map_or` will correctly return `false`.
-        self.edges.get(a).map_or(false, |edge_list| {
+        // `get` will return `None`, and the `is_some_and` will correctly return `false`.
+        self.edges.get(a).is_some_and(|edge_list| {
             // Then we simply check if any edge in that list points to `b`
             // and that the target node is not tombstoned.
             edge_list
@@ -1,5 +1,7 @@
+mod graph_algo;
 mod graph_freeze;
 mod graph_mut;
+mod graph_traversal;
 mod graph_view;
 
 pub struct DynamicGraph<N, W> {
@@ -18,6 +20,11 @@ pub struct DynamicGraph<N, W> {
 
     // Index of the designated root node.
     root_index: Option<usize>,
+
+    // Optional reverse adjacency list: for each target node, the sources of its
+    // incoming edges. When enabled, it is kept in sync by all `GraphMut` methods and
+    // makes `inbound_edges` O(in-degree) instead of a full O(V + E) scan.
+    reverse_edges: Option<Vec<Vec<usize>>>,
 }
 
 impl<N, W> DynamicGraph<N, W> {
@@ -46,6 +53,7 @@ impl<N, W> DynamicGraph<N, W> {
             nodes: Vec::new(),
             edges: Vec::new(),
             root_index: None,
+            reverse_edges: None,
         }
     }
 
@@ -85,6 +93,7 @@ impl<N, W> DynamicGraph<N, W> {
             nodes: Vec::with_capacity(num_nodes),
             edges: Vec::with_capacity(num_nodes),
             root_index: None,
+            reverse_edges: None,
         }
     }
 }
@@ -150,6 +159,7 @@ impl<N, W> DynamicGraph<N, W> {
             root_index,
             // When building from parts, we assume the user has already handled capacity.
             num_edges_per_node: None,
+            reverse_edges: None,
         }
     }
 
@@ -169,11 +179,46 @@ impl<N, W> DynamicGraph<N, W> {
     /// - `edges`: A `Vec<Vec<(usize, W)>>` representing the adjacency list.
     /// - `root_index`: An `Option<usize>` for the root node.
     ///
+    #[allow(clippy::type_complexity)]
     pub fn to_parts(self) -> (Vec<Option<N>>, Vec<Vec<(usize, W)>>, Option<usize>) {
         (self.nodes, self.edges, self.root_index)
     }
 }
 
+impl<N, W> DynamicGraph<N, W> {
+    /// Enables the reverse index that backs `GraphTraversal::inbound_edges`.
+    ///
+    /// Without the index, `inbound_edges` has to scan every edge of the graph. With it,
+    /// the incoming edges of each node are kept in a reverse adjacency list that all
+    /// `GraphMut` methods update, at the cost of one extra `usize` per edge.
+    ///
+    /// Building the index is O(V + E). Calling this on a graph that already has the
+    /// index is a no-op.
+    pub fn enable_reverse_index(&mut self) {
+        if self.reverse_edges.is_some() {
+            return;
+        }
+
+        let mut reverse_edges = vec![Vec::new(); self.nodes.len()];
+        for (source, edge_list) in self.edges.iter().enumerate() {
+            for (target, _) in edge_list {
+                reverse_edges[*target].push(source);
+            }
+        }
+        self.reverse_edges = Some(reverse_edges);
+    }
+
+    /// Disables and drops the reverse index, freeing its memory.
+    pub fn disable_reverse_index(&mut self) {
+        self.reverse_edges = None;
+    }
+
+    /// Returns `true` if the reverse index for `inbound_edges` is enabled.
+    pub fn has_reverse_index(&self) -> bool {
+        self.reverse_edges.is_some()
+    }
+}
+
 impl<N, W> Default for DynamicGraph<N, W> {
     fn default() -> Self {
         Self::new()
@@ -192,6 +237,7 @@ impl<N, W> DynamicGraph<N, W> {
             nodes,
             edges,
             root_index,
+            reverse_edges: None,
         }
     }
 }
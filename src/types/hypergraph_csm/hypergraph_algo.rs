@@ -0,0 +1,195 @@
+use crate::types::graph_csm::CsrAdjacency;
+use crate::{CsmGraph, GraphTraversal, Hypergraph, HypergraphAlgorithms};
+use std::collections::VecDeque;
+
+impl<N, E> HypergraphAlgorithms<N, E> for Hypergraph<N, E> {
+    /// Builds the s-line graph by counting pairwise hyperedge overlaps.
+    ///
+    /// For each hyperedge, the algorithm walks its members and, through the
+    /// node-to-hyperedge CSR, every other hyperedge incident to those members. A
+    /// dense counter array accumulates the overlap sizes, and only the touched
+    /// entries are reset afterwards. The adjacency is symmetric, so the forward CSR
+    /// is reused as the backward CSR.
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** O(Σ deg(v)²) over all nodes v, plus sorting each
+    ///   adjacency list, since every pair of hyperedges sharing a node is visited
+    ///   once per shared node.
+    /// - **Space Complexity:** O(H + L) for the counters and the L line graph edges.
+    fn s_line_graph(&self, s: usize) -> CsmGraph<usize, usize> {
+        let s = s.max(1);
+        let num_hyperedges = self.hyperedges.len();
+
+        let mut overlaps = vec![0; num_hyperedges];
+        let mut touched = Vec::new();
+
+        let mut offsets = Vec::with_capacity(num_hyperedges + 1);
+        let mut targets = Vec::new();
+        let mut weights = Vec::new();
+        offsets.push(0);
+
+        for e in 0..num_hyperedges {
+            // Keep the s-adjacent hyperedges, sorted for binary search lookups.
+            self.for_each_s_neighbor(e, s, &mut overlaps, &mut touched, |f, overlap| {
+                targets.push(f);
+                weights.push(overlap);
+            });
+            offsets.push(targets.len());
+        }
+
+        let forward_edges = CsrAdjacency {
+            offsets,
+            targets,
+            weights,
+        };
+        // The line graph is undirected, so the transpose equals the forward structure.
+        let backward_edges = CsrAdjacency {
+            offsets: forward_edges.offsets.clone(),
+            targets: forward_edges.targets.clone(),
+            weights: forward_edges.weights.clone(),
+        };
+
+        CsmGraph::construct(
+            (0..num_hyperedges).collect(),
+            forward_edges,
+            backward_edges,
+            None,
+        )
+    }
+
+    /// Computes the s-connected components with a BFS over the s-line graph.
+    fn s_connected_components(&self, s: usize) -> Vec<Vec<usize>> {
+        let s = s.max(1);
+        let line_graph = self.s_line_graph(s);
+        let num_hyperedges = self.hyperedges.len();
+
+        let mut visited = vec![false; num_hyperedges];
+        let mut queue = VecDeque::new();
+        let mut components = Vec::new();
+
+        for e in 0..num_hyperedges {
+            let size =
+                self.hyperedge_incidence.offsets[e + 1] - self.hyperedge_incidence.offsets[e];
+            if visited[e] || size < s {
+                continue;
+            }
+
+            let mut component = Vec::new();
+            visited[e] = true;
+            queue.push_back(e);
+            while let Some(u) = queue.pop_front() {
+                component.push(u);
+                // The unwrap is safe because `u` is a valid line graph node.
+                for v in line_graph.outbound_edges(u).unwrap() {
+                    if !visited[v] {
+                        visited[v] = true;
+                        queue.push_back(v);
+                    }
+                }
+            }
+            component.sort_unstable();
+            components.push(component);
+        }
+
+        components
+    }
+
+    /// Computes the s-distance with a BFS directly on the incidence CSR.
+    ///
+    /// Instead of building the s-line graph, the BFS counts the overlaps of each
+    /// dequeued hyperedge on the fly with the same helper as `s_line_graph`, and stops
+    /// as soon as `target` is reached.
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** O(Σ deg(v)) over the members v of every visited
+    ///   hyperedge, plus sorting their neighbors, which is at most O(Σ deg(v)²) over
+    ///   all nodes.
+    /// - **Space Complexity:** O(H) for the distances and overlap counters.
+    fn s_distance(&self, s: usize, source: usize, target: usize) -> Option<usize> {
+        let s = s.max(1);
+        let num_hyperedges = self.hyperedges.len();
+        let size = |e: usize| {
+            self.hyperedge_incidence.offsets[e + 1] - self.hyperedge_incidence.offsets[e]
+        };
+
+        // A hyperedge with fewer than `s` nodes cannot take part in any s-walk.
+        if source >= num_hyperedges || target >= num_hyperedges {
+            return None;
+        }
+        if size(source) < s || size(target) < s {
+            return None;
+        }
+        if source == target {
+            return Some(0);
+        }
+
+        let mut distances: Vec<Option<usize>> = vec![None; num_hyperedges];
+        let mut overlaps = vec![0; num_hyperedges];
+        let mut touched = Vec::new();
+        let mut queue = VecDeque::new();
+
+        distances[source] = Some(0);
+        queue.push_back(source);
+
+        while let Some(e) = queue.pop_front() {
+            let next_distance = distances[e].map(|d| d + 1);
+            self.for_each_s_neighbor(e, s, &mut overlaps, &mut touched, |f, _| {
+                if distances[f].is_none() {
+                    distances[f] = next_distance;
+                    queue.push_back(f);
+                }
+            });
+            if distances[target].is_some() {
+                return distances[target];
+            }
+        }
+
+        None
+    }
+}
+
+impl<N, E> Hypergraph<N, E> {
+    /// Calls `f(neighbor, overlap)` for every other hyperedge that shares at least `s`
+    /// nodes with hyperedge `e`, in ascending order of the neighbor index.
+    ///
+    /// The algorithm walks the members of `e` and, through the node-to-hyperedge CSR,
+    /// every other hyperedge incident to those members. The dense `overlaps` counters
+    /// must be zero on entry, and only the `touched` entries are reset afterwards, so
+    /// both buffers can be reused across calls.
+    fn for_each_s_neighbor(
+        &self,
+        e: usize,
+        s: usize,
+        overlaps: &mut [usize],
+        touched: &mut Vec<usize>,
+        mut f: impl FnMut(usize, usize),
+    ) {
+        let start = self.hyperedge_incidence.offsets[e];
+        let end = self.hyperedge_incidence.offsets[e + 1];
+
+        // Accumulate the number of shared nodes with every other hyperedge.
+        for &v in &self.hyperedge_incidence.targets[start..end] {
+            let v_start = self.node_incidence.offsets[v];
+            let v_end = self.node_incidence.offsets[v + 1];
+            for &h in &self.node_incidence.targets[v_start..v_end] {
+                if h != e {
+                    if overlaps[h] == 0 {
+                        touched.push(h);
+                    }
+                    overlaps[h] += 1;
+                }
+            }
+        }
+
+        touched.sort_unstable();
+        for &h in touched.iter() {
+            if overlaps[h] >= s {
+                f(h, overlaps[h]);
+            }
+            overlaps[h] = 0;
+        }
+        touched.clear();
+    }
+}
@@ -0,0 +1,32 @@
+use crate::{GraphError, Hypergraph, HypergraphTraversal, HypergraphView};
+
+impl<N, E> HypergraphTraversal<N, E> for Hypergraph<N, E> {
+    /// Returns a non-allocating iterator over the member nodes of hyperedge `e`.
+    ///
+    /// # Errors
+    /// Returns `GraphError::HyperedgeNotFound` if the hyperedge index `e` is out of bounds.
+    fn hyperedge_nodes(&self, e: usize) -> Result<impl Iterator<Item = usize> + '_, GraphError> {
+        if !self.contains_hyperedge(e) {
+            return Err(GraphError::HyperedgeNotFound(e));
+        }
+
+        let start = self.hyperedge_incidence.offsets[e];
+        let end = self.hyperedge_incidence.offsets[e + 1];
+        Ok(self.hyperedge_incidence.targets[start..end].iter().copied())
+    }
+
+    /// Returns a non-allocating iterator over the hyperedges incident to node `a`.
+    ///
+    /// # Errors
+    /// Returns `GraphError::NodeNotFound` if the node index `a` is out of bounds.
+    fn node_hyperedges(&self, a: usize) -> Result<impl Iterator<Item = usize> + '_, GraphError> {
+        if !self.contains_node(a) {
+            return Err(GraphError::NodeNotFound(a));
+        }
+
+        // Use the transposed incidence structure for the node-to-hyperedge direction.
+        let start = self.node_incidence.offsets[a];
+        let end = self.node_incidence.offsets[a + 1];
+        Ok(self.node_incidence.targets[start..end].iter().copied())
+    }
+}
@@ -0,0 +1,35 @@
+use crate::{DynamicHypergraph, Hypergraph, HypergraphUnfreezable};
+
+/// Converts a static `Hypergraph` back into a mutable `DynamicHypergraph`.
+///
+/// Like `CsmGraph::unfreeze`, this operation is infallible: a `Hypergraph` can only
+/// be created by `.freeze()`, which guarantees that its incidence structures are
+/// consistent, so the transformation has no failure modes a caller could handle.
+impl<N, E> HypergraphUnfreezable<N, E> for Hypergraph<N, E> {
+    /// Consumes the `Hypergraph` and converts it back into a mutable `DynamicHypergraph`.
+    ///
+    /// Node and hyperedge payloads are moved, not cloned. Only the member lists are
+    /// rebuilt from the hyperedge-to-node CSR structure.
+    fn unfreeze(self) -> DynamicHypergraph<N, E> {
+        let Self {
+            nodes,
+            hyperedges,
+            hyperedge_incidence,
+            node_incidence: _, // Not needed for reconstruction, will be dropped.
+        } = self;
+
+        let dynamic_nodes: Vec<Option<N>> = nodes.into_iter().map(Some).collect();
+
+        let members: Vec<Vec<usize>> = (0..hyperedges.len())
+            .map(|e| {
+                let start = hyperedge_incidence.offsets[e];
+                let end = hyperedge_incidence.offsets[e + 1];
+                hyperedge_incidence.targets[start..end].to_vec()
+            })
+            .collect();
+
+        let dynamic_hyperedges: Vec<Option<E>> = hyperedges.into_iter().map(Some).collect();
+
+        DynamicHypergraph::construct(dynamic_nodes, dynamic_hyperedges, members)
+    }
+}
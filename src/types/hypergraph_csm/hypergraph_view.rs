@@ -0,0 +1,60 @@
+use crate::{Hypergraph, HypergraphView};
+
+impl<N, E> HypergraphView<N, E> for Hypergraph<N, E> {
+    /// Checks if the hypergraph is in a frozen, high-performance state.
+    /// For `Hypergraph`, this is always true by definition.
+    fn is_frozen(&self) -> bool {
+        true
+    }
+
+    /// Checks if a node exists at the given index.
+    /// In a frozen `Hypergraph`, the node list is compact, so a simple bounds check is sufficient.
+    fn contains_node(&self, index: usize) -> bool {
+        index < self.nodes.len()
+    }
+
+    /// Retrieves a reference to the payload of a node at the given index.
+    fn get_node(&self, index: usize) -> Option<&N> {
+        self.nodes.get(index)
+    }
+
+    /// Returns the total number of nodes in the hypergraph.
+    fn number_nodes(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Checks if a hyperedge exists at the given index.
+    fn contains_hyperedge(&self, index: usize) -> bool {
+        index < self.hyperedges.len()
+    }
+
+    /// Retrieves a reference to the payload of a hyperedge at the given index.
+    fn get_hyperedge(&self, index: usize) -> Option<&E> {
+        self.hyperedges.get(index)
+    }
+
+    /// Returns the total number of hyperedges in the hypergraph.
+    fn number_hyperedges(&self) -> usize {
+        self.hyperedges.len()
+    }
+
+    /// Checks if `node` is a member of `hyperedge`.
+    /// The member list is sorted during `.freeze()`, so this is an O(log k) binary search.
+    fn is_incident(&self, node: usize, hyperedge: usize) -> bool {
+        if !self.contains_hyperedge(hyperedge) {
+            return false;
+        }
+
+        let start = self.hyperedge_incidence.offsets[hyperedge];
+        let end = self.hyperedge_incidence.offsets[hyperedge + 1];
+        self.hyperedge_incidence.targets[start..end]
+            .binary_search(&node)
+            .is_ok()
+    }
+
+    /// Returns the total number of node-hyperedge incidences.
+    /// This is an O(1) operation, as it's just the length of the targets vector.
+    fn number_incidences(&self) -> usize {
+        self.hyperedge_incidence.targets.len()
+    }
+}
@@ -0,0 +1,129 @@
+mod hypergraph_algo;
+mod hypergraph_traversal;
+mod hypergraph_unfreeze;
+mod hypergraph_view;
+
+use crate::types::graph_csm::CsrAdjacency;
+
+/// A static, high-performance hypergraph in which each hyperedge connects an
+/// arbitrary set of nodes and carries its own payload.
+///
+/// The hypergraph is stored in the bipartite representation of the NWHypergraph
+/// model: nodes and hyperedges form the two partitions, and the incidence
+/// relation is kept in two mutually indexed CSR structures. One maps each
+/// hyperedge to its member nodes, the other maps each node to the hyperedges
+/// it belongs to. Both adjacency lists are sorted by index.
+pub struct Hypergraph<N, E> {
+    // Node payloads, indexed directly by `usize`.
+    nodes: Vec<N>,
+
+    // Hyperedge payloads, indexed directly by `usize`.
+    hyperedges: Vec<E>,
+
+    // CSR structure mapping each hyperedge to its member nodes.
+    hyperedge_incidence: CsrAdjacency<()>,
+
+    // CSR structure mapping each node to its incident hyperedges.
+    // This is the transpose of `hyperedge_incidence`.
+    node_incidence: CsrAdjacency<()>,
+}
+
+impl<N, E> Hypergraph<N, E> {
+    /// Creates a new, empty `Hypergraph`.
+    ///
+    /// The hypergraph will have zero nodes and zero hyperedges.
+    pub fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            hyperedges: Vec::new(),
+            // Initialize with a valid empty CSR state. The offsets vector must
+            // contain a single `0` to correctly represent the `V + 1` length rule, where V=0.
+            hyperedge_incidence: CsrAdjacency {
+                offsets: vec![0],
+                ..Default::default()
+            },
+            node_incidence: CsrAdjacency {
+                offsets: vec![0],
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl<N, E> Hypergraph<N, E> {
+    // Internal helper for freeze
+    pub(crate) fn construct(
+        nodes: Vec<N>,
+        hyperedges: Vec<E>,
+        hyperedge_incidence: CsrAdjacency<()>,
+        node_incidence: CsrAdjacency<()>,
+    ) -> Self {
+        Self {
+            nodes,
+            hyperedges,
+            hyperedge_incidence,
+            node_incidence,
+        }
+    }
+}
+
+impl<N, E> Default for Hypergraph<N, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Builds a weightless CSR structure from per-row adjacency lists together with its transpose.
+///
+/// Each row in `rows` must be sorted and contain only indices below `num_columns`.
+/// Because rows are processed in ascending order, the rows of the transpose come
+/// out sorted as well, so no additional sorting pass is needed.
+///
+/// # Returns
+/// A tuple `(forward, transposed)` where `forward` has `rows.len()` rows and
+/// `transposed` has `num_columns` rows.
+pub(crate) fn build_incidence_csr(
+    rows: &[Vec<usize>],
+    num_columns: usize,
+) -> (CsrAdjacency<()>, CsrAdjacency<()>) {
+    let total: usize = rows.iter().map(|row| row.len()).sum();
+
+    // Forward CSR: a straight concatenation of the rows.
+    let mut fwd_offsets = Vec::with_capacity(rows.len() + 1);
+    let mut fwd_targets = Vec::with_capacity(total);
+    fwd_offsets.push(0);
+    for row in rows {
+        fwd_targets.extend_from_slice(row);
+        fwd_offsets.push(fwd_targets.len());
+    }
+
+    // Transposed CSR: count column degrees, prefix sum, then place.
+    let mut back_offsets = vec![0; num_columns + 1];
+    for &column in &fwd_targets {
+        back_offsets[column + 1] += 1;
+    }
+    for i in 0..num_columns {
+        back_offsets[i + 1] += back_offsets[i];
+    }
+
+    let mut write_heads = back_offsets.clone();
+    let mut back_targets = vec![0; total];
+    for (row_index, row) in rows.iter().enumerate() {
+        for &column in row {
+            back_targets[write_heads[column]] = row_index;
+            write_heads[column] += 1;
+        }
+    }
+
+    let forward = CsrAdjacency {
+        offsets: fwd_offsets,
+        targets: fwd_targets,
+        weights: vec![(); total],
+    };
+    let transposed = CsrAdjacency {
+        offsets: back_offsets,
+        targets: back_targets,
+        weights: vec![(); total],
+    };
+    (forward, transposed)
+}
@@ -0,0 +1,63 @@
+use crate::{DirectedHypergraph, DirectedHypergraphTraversal, GraphError, HypergraphView};
+
+impl<N, E> DirectedHypergraphTraversal<N, E> for DirectedHypergraph<N, E> {
+    /// Returns a non-allocating iterator over the tail nodes of hyperedge `e`.
+    ///
+    /// # Errors
+    /// Returns `GraphError::HyperedgeNotFound` if the hyperedge index `e` is out of bounds.
+    fn hyperedge_tail(&self, e: usize) -> Result<impl Iterator<Item = usize> + '_, GraphError> {
+        if !self.contains_hyperedge(e) {
+            return Err(GraphError::HyperedgeNotFound(e));
+        }
+
+        let start = self.tails.offsets[e];
+        let end = self.tails.offsets[e + 1];
+        Ok(self.tails.targets[start..end].iter().copied())
+    }
+
+    /// Returns a non-allocating iterator over the head nodes of hyperedge `e`.
+    ///
+    /// # Errors
+    /// Returns `GraphError::HyperedgeNotFound` if the hyperedge index `e` is out of bounds.
+    fn hyperedge_head(&self, e: usize) -> Result<impl Iterator<Item = usize> + '_, GraphError> {
+        if !self.contains_hyperedge(e) {
+            return Err(GraphError::HyperedgeNotFound(e));
+        }
+
+        let start = self.heads.offsets[e];
+        let end = self.heads.offsets[e + 1];
+        Ok(self.heads.targets[start..end].iter().copied())
+    }
+
+    /// Returns a non-allocating iterator over the hyperedges that have node `a` in their tail.
+    ///
+    /// # Errors
+    /// Returns `GraphError::NodeNotFound` if the node index `a` is out of bounds.
+    fn outbound_hyperedges(
+        &self,
+        a: usize,
+    ) -> Result<impl Iterator<Item = usize> + '_, GraphError> {
+        if !self.contains_node(a) {
+            return Err(GraphError::NodeNotFound(a));
+        }
+
+        let start = self.forward_incidence.offsets[a];
+        let end = self.forward_incidence.offsets[a + 1];
+        Ok(self.forward_incidence.targets[start..end].iter().copied())
+    }
+
+    /// Returns a non-allocating iterator over the hyperedges that have node `a` in their head.
+    ///
+    /// # Errors
+    /// Returns `GraphError::NodeNotFound` if the node index `a` is out of bounds.
+    fn inbound_hyperedges(&self, a: usize) -> Result<impl Iterator<Item = usize> + '_, GraphError> {
+        if !self.contains_node(a) {
+            return Err(GraphError::NodeNotFound(a));
+        }
+
+        // Use the backward incidence structure for efficient inbound traversal.
+        let start = self.backward_incidence.offsets[a];
+        let end = self.backward_incidence.offsets[a + 1];
+        Ok(self.backward_incidence.targets[start..end].iter().copied())
+    }
+}
@@ -0,0 +1,34 @@
+use crate::types::graph_csm::CsrAdjacency;
+use crate::{DirectedHypergraph, DirectedHypergraphUnfreezable, DynamicDirectedHypergraph};
+
+/// Converts a static `DirectedHypergraph` back into a mutable `DynamicDirectedHypergraph`.
+///
+/// This operation is infallible for the same reasons as `Hypergraph::unfreeze`.
+impl<N, E> DirectedHypergraphUnfreezable<N, E> for DirectedHypergraph<N, E> {
+    /// Consumes the `DirectedHypergraph` and converts it back into a mutable
+    /// `DynamicDirectedHypergraph`, moving all payloads.
+    fn unfreeze(self) -> DynamicDirectedHypergraph<N, E> {
+        let Self {
+            nodes,
+            hyperedges,
+            tails,
+            heads,
+            forward_incidence: _, // Transposes are not needed for reconstruction.
+            backward_incidence: _,
+        } = self;
+
+        let num_hyperedges = hyperedges.len();
+        let rows = |csr: &CsrAdjacency<()>| -> Vec<Vec<usize>> {
+            (0..num_hyperedges)
+                .map(|e| csr.targets[csr.offsets[e]..csr.offsets[e + 1]].to_vec())
+                .collect()
+        };
+
+        DynamicDirectedHypergraph::construct(
+            nodes.into_iter().map(Some).collect(),
+            hyperedges.into_iter().map(Some).collect(),
+            rows(&tails),
+            rows(&heads),
+        )
+    }
+}
@@ -0,0 +1,63 @@
+use crate::{DirectedHypergraph, HypergraphView};
+
+impl<N, E> HypergraphView<N, E> for DirectedHypergraph<N, E> {
+    /// Checks if the hypergraph is in a frozen, high-performance state.
+    /// For `DirectedHypergraph`, this is always true by definition.
+    fn is_frozen(&self) -> bool {
+        true
+    }
+
+    /// Checks if a node exists at the given index.
+    fn contains_node(&self, index: usize) -> bool {
+        index < self.nodes.len()
+    }
+
+    /// Retrieves a reference to the payload of a node at the given index.
+    fn get_node(&self, index: usize) -> Option<&N> {
+        self.nodes.get(index)
+    }
+
+    /// Returns the total number of nodes in the hypergraph.
+    fn number_nodes(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Checks if a hyperedge exists at the given index.
+    fn contains_hyperedge(&self, index: usize) -> bool {
+        index < self.hyperedges.len()
+    }
+
+    /// Retrieves a reference to the payload of a hyperedge at the given index.
+    fn get_hyperedge(&self, index: usize) -> Option<&E> {
+        self.hyperedges.get(index)
+    }
+
+    /// Returns the total number of hyperedges in the hypergraph.
+    fn number_hyperedges(&self) -> usize {
+        self.hyperedges.len()
+    }
+
+    /// Checks if `node` is in the tail or the head of `hyperedge`.
+    /// Both sets are sorted during `.freeze()`, so this uses two binary searches.
+    fn is_incident(&self, node: usize, hyperedge: usize) -> bool {
+        if !self.contains_hyperedge(hyperedge) {
+            return false;
+        }
+
+        let start = self.tails.offsets[hyperedge];
+        let end = self.tails.offsets[hyperedge + 1];
+        if self.tails.targets[start..end].binary_search(&node).is_ok() {
+            return true;
+        }
+
+        let start = self.heads.offsets[hyperedge];
+        let end = self.heads.offsets[hyperedge + 1];
+        self.heads.targets[start..end].binary_search(&node).is_ok()
+    }
+
+    /// Returns the total number of tail and head incidences.
+    /// A node that is in both the tail and the head of a hyperedge counts twice.
+    fn number_incidences(&self) -> usize {
+        self.tails.targets.len() + self.heads.targets.len()
+    }
+}
@@ -0,0 +1,80 @@
+mod hypergraph_traversal;
+mod hypergraph_unfreeze;
+mod hypergraph_view;
+
+use crate::types::graph_csm::CsrAdjacency;
+
+/// A static, high-performance hypergraph of directed hyperedges.
+///
+/// Each hyperedge leads from a tail node set to a head node set, which expresses
+/// many-to-many dependencies such as "A and B jointly cause C and D". Like
+/// `Hypergraph`, it uses the bipartite representation, but with four CSR
+/// structures: the tail and head sets of each hyperedge, and their transposes,
+/// which map each node to the hyperedges leaving it (forward) and entering it
+/// (backward). All adjacency lists are sorted by index.
+pub struct DirectedHypergraph<N, E> {
+    // Node payloads, indexed directly by `usize`.
+    nodes: Vec<N>,
+
+    // Hyperedge payloads, indexed directly by `usize`.
+    hyperedges: Vec<E>,
+
+    // CSR structure mapping each hyperedge to its tail (source) nodes.
+    tails: CsrAdjacency<()>,
+
+    // CSR structure mapping each hyperedge to its head (target) nodes.
+    heads: CsrAdjacency<()>,
+
+    // CSR structure for forward traversal: node -> hyperedges with the node in their tail.
+    forward_incidence: CsrAdjacency<()>,
+
+    // CSR structure for backward traversal: node -> hyperedges with the node in their head.
+    backward_incidence: CsrAdjacency<()>,
+}
+
+impl<N, E> DirectedHypergraph<N, E> {
+    /// Creates a new, empty `DirectedHypergraph`.
+    ///
+    /// The hypergraph will have zero nodes and zero hyperedges.
+    pub fn new() -> Self {
+        // Initialize with a valid empty CSR state. The offsets vector must
+        // contain a single `0` to correctly represent the `V + 1` length rule, where V=0.
+        let empty = || CsrAdjacency {
+            offsets: vec![0],
+            ..Default::default()
+        };
+        Self {
+            nodes: Vec::new(),
+            hyperedges: Vec::new(),
+            tails: empty(),
+            heads: empty(),
+            forward_incidence: empty(),
+            backward_incidence: empty(),
+        }
+    }
+}
+
+impl<N, E> DirectedHypergraph<N, E> {
+    // Internal helper for freeze
+    pub(crate) fn construct(
+        nodes: Vec<N>,
+        hyperedges: Vec<E>,
+        (tails, forward_incidence): (CsrAdjacency<()>, CsrAdjacency<()>),
+        (heads, backward_incidence): (CsrAdjacency<()>, CsrAdjacency<()>),
+    ) -> Self {
+        Self {
+            nodes,
+            hyperedges,
+            tails,
+            heads,
+            forward_incidence,
+            backward_incidence,
+        }
+    }
+}
+
+impl<N, E> Default for DirectedHypergraph<N, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,61 @@
+use crate::types::hypergraph_csm::build_incidence_csr;
+use crate::{DirectedHypergraph, DirectedHypergraphFreezable, DynamicDirectedHypergraph};
+
+impl<N, E> DirectedHypergraphFreezable<N, E> for DynamicDirectedHypergraph<N, E> {
+    /// Consumes the dynamic hypergraph to create a static, high-performance `DirectedHypergraph`.
+    ///
+    /// This is an O(V + H + I) operation, where I is the number of tail and head
+    /// incidences. It removes tombstoned nodes and hyperedges, re-indexes both
+    /// partitions, and builds the tail and head CSR structures together with their
+    /// forward and backward transposes.
+    ///
+    /// Like `DynamicHypergraph::freeze`, this method is infallible and moves all payloads.
+    fn freeze(self) -> DirectedHypergraph<N, E> {
+        let Self {
+            nodes: old_nodes,
+            hyperedges: old_hyperedges,
+            tails: old_tails,
+            heads: old_heads,
+        } = self;
+
+        // --- Compact the node partition ---
+        let mut compacted_nodes = Vec::with_capacity(old_nodes.len());
+        let mut node_remap = vec![None; old_nodes.len()];
+        for (old_index, node_opt) in old_nodes.into_iter().enumerate() {
+            if let Some(node) = node_opt {
+                node_remap[old_index] = Some(compacted_nodes.len());
+                compacted_nodes.push(node);
+            }
+        }
+        compacted_nodes.shrink_to_fit();
+
+        // --- Compact the hyperedge partition and remap both endpoint sets ---
+        // The remapping is monotone, so every list stays sorted.
+        let remap = |list: Vec<usize>| -> Vec<usize> {
+            list.into_iter()
+                .filter_map(|old_node| node_remap[old_node])
+                .collect()
+        };
+
+        let mut compacted_hyperedges = Vec::with_capacity(old_hyperedges.len());
+        let mut tail_rows = Vec::with_capacity(old_hyperedges.len());
+        let mut head_rows = Vec::with_capacity(old_hyperedges.len());
+        for ((edge_opt, tail), head) in old_hyperedges.into_iter().zip(old_tails).zip(old_heads) {
+            if let Some(payload) = edge_opt {
+                compacted_hyperedges.push(payload);
+                tail_rows.push(remap(tail));
+                head_rows.push(remap(head));
+            }
+        }
+        compacted_hyperedges.shrink_to_fit();
+
+        // --- Build the four incidence structures ---
+        let num_nodes = compacted_nodes.len();
+        DirectedHypergraph::construct(
+            compacted_nodes,
+            compacted_hyperedges,
+            build_incidence_csr(&tail_rows, num_nodes),
+            build_incidence_csr(&head_rows, num_nodes),
+        )
+    }
+}
@@ -0,0 +1,119 @@
+use crate::{DirectedHypergraphMut, DynamicDirectedHypergraph, GraphError, HypergraphView};
+
+impl<N, E> DirectedHypergraphMut<N, E> for DynamicDirectedHypergraph<N, E> {
+    /// Adds a new node to the hypergraph and returns its stable index.
+    ///
+    /// This operation is amortized O(1).
+    fn add_node(&mut self, node: N) -> usize {
+        let index = self.nodes.len();
+        self.nodes.push(Some(node));
+        index
+    }
+
+    /// Updates the payload of an existing, non-tombstoned node.
+    ///
+    /// # Errors
+    /// Returns `GraphError::NodeNotFound` if the index is out of bounds or if the
+    /// node at that index has already been removed.
+    fn update_node(&mut self, index: usize, node: N) -> Result<(), GraphError> {
+        match self.nodes.get_mut(index) {
+            Some(node_slot) if node_slot.is_some() => {
+                *node_slot = Some(node);
+                Ok(())
+            }
+            _ => Err(GraphError::NodeNotFound(index)),
+        }
+    }
+
+    /// Removes a node from the hypergraph, effectively "tombstoning" it.
+    ///
+    /// This is an O(1) operation. The node stays in the tail and head lists of its
+    /// hyperedges but is filtered out by all views and dropped during `freeze`.
+    ///
+    /// # Errors
+    /// Returns `GraphError::NodeNotFound` if the index is out of bounds or if the
+    /// node at that index has already been removed.
+    fn remove_node(&mut self, index: usize) -> Result<(), GraphError> {
+        match self.nodes.get_mut(index) {
+            Some(node_slot) if node_slot.is_some() => {
+                *node_slot = None; // "Tombstone" the node
+                Ok(())
+            }
+            _ => Err(GraphError::NodeNotFound(index)),
+        }
+    }
+
+    /// Adds a directed hyperedge from `tail` to `head` and returns its stable index.
+    ///
+    /// Both sets are stored sorted and deduplicated. This operation is O(k log k)
+    /// for k endpoints.
+    ///
+    /// # Errors
+    /// Returns `GraphError::NodeNotFound` for the first tail or head index that does
+    /// not exist or has been removed. The hypergraph is unchanged in that case.
+    fn add_hyperedge(
+        &mut self,
+        tail: &[usize],
+        head: &[usize],
+        payload: E,
+    ) -> Result<usize, GraphError> {
+        if let Some(&missing) = tail.iter().chain(head).find(|&&n| !self.contains_node(n)) {
+            return Err(GraphError::NodeNotFound(missing));
+        }
+
+        let mut tail_list = tail.to_vec();
+        tail_list.sort_unstable();
+        tail_list.dedup();
+
+        let mut head_list = head.to_vec();
+        head_list.sort_unstable();
+        head_list.dedup();
+
+        let index = self.hyperedges.len();
+        self.hyperedges.push(Some(payload));
+        self.tails.push(tail_list);
+        self.heads.push(head_list);
+        Ok(index)
+    }
+
+    /// Updates the payload of an existing, non-tombstoned hyperedge.
+    ///
+    /// # Errors
+    /// Returns `GraphError::HyperedgeNotFound` if the index is out of bounds or if the
+    /// hyperedge at that index has already been removed.
+    fn update_hyperedge(&mut self, index: usize, payload: E) -> Result<(), GraphError> {
+        match self.hyperedges.get_mut(index) {
+            Some(edge_slot) if edge_slot.is_some() => {
+                *edge_slot = Some(payload);
+                Ok(())
+            }
+            _ => Err(GraphError::HyperedgeNotFound(index)),
+        }
+    }
+
+    /// Removes a hyperedge, effectively "tombstoning" it.
+    ///
+    /// # Errors
+    /// Returns `GraphError::HyperedgeNotFound` if the index is out of bounds or if the
+    /// hyperedge at that index has already been removed.
+    fn remove_hyperedge(&mut self, index: usize) -> Result<(), GraphError> {
+        match self.hyperedges.get_mut(index) {
+            Some(edge_slot) if edge_slot.is_some() => {
+                *edge_slot = None; // "Tombstone" the hyperedge
+                // Release the endpoint lists; they are no longer reachable.
+                self.tails[index] = Vec::new();
+                self.heads[index] = Vec::new();
+                Ok(())
+            }
+            _ => Err(GraphError::HyperedgeNotFound(index)),
+        }
+    }
+
+    /// Clears all nodes and hyperedges, resetting the hypergraph to an empty state.
+    fn clear(&mut self) {
+        self.nodes.clear();
+        self.hyperedges.clear();
+        self.tails.clear();
+        self.heads.clear();
+    }
+}
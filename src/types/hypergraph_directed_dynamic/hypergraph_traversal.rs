@@ -0,0 +1,70 @@
+use crate::{DirectedHypergraphTraversal, DynamicDirectedHypergraph, GraphError, HypergraphView};
+
+impl<N, E> DirectedHypergraphTraversal<N, E> for DynamicDirectedHypergraph<N, E> {
+    /// Returns an iterator over the live tail nodes of hyperedge `e`.
+    ///
+    /// # Errors
+    /// Returns `GraphError::HyperedgeNotFound` if the hyperedge does not exist or has been removed.
+    fn hyperedge_tail(&self, e: usize) -> Result<impl Iterator<Item = usize> + '_, GraphError> {
+        if !self.contains_hyperedge(e) {
+            return Err(GraphError::HyperedgeNotFound(e));
+        }
+
+        Ok(self.tails[e]
+            .iter()
+            .copied()
+            .filter(|&n| self.contains_node(n)))
+    }
+
+    /// Returns an iterator over the live head nodes of hyperedge `e`.
+    ///
+    /// # Errors
+    /// Returns `GraphError::HyperedgeNotFound` if the hyperedge does not exist or has been removed.
+    fn hyperedge_head(&self, e: usize) -> Result<impl Iterator<Item = usize> + '_, GraphError> {
+        if !self.contains_hyperedge(e) {
+            return Err(GraphError::HyperedgeNotFound(e));
+        }
+
+        Ok(self.heads[e]
+            .iter()
+            .copied()
+            .filter(|&n| self.contains_node(n)))
+    }
+
+    /// Returns an iterator over the live hyperedges that have node `a` in their tail.
+    ///
+    /// The dynamic hypergraph does not maintain node-to-hyperedge indices, so this
+    /// scans every tail list with a binary search. Freeze the hypergraph for O(1)
+    /// access to the outbound hyperedges.
+    ///
+    /// # Errors
+    /// Returns `GraphError::NodeNotFound` if the node does not exist or has been removed.
+    fn outbound_hyperedges(
+        &self,
+        a: usize,
+    ) -> Result<impl Iterator<Item = usize> + '_, GraphError> {
+        if !self.contains_node(a) {
+            return Err(GraphError::NodeNotFound(a));
+        }
+
+        Ok((0..self.tails.len()).filter(move |&e| {
+            self.contains_hyperedge(e) && self.tails[e].binary_search(&a).is_ok()
+        }))
+    }
+
+    /// Returns an iterator over the live hyperedges that have node `a` in their head.
+    ///
+    /// Like `outbound_hyperedges`, this scans every head list.
+    ///
+    /// # Errors
+    /// Returns `GraphError::NodeNotFound` if the node does not exist or has been removed.
+    fn inbound_hyperedges(&self, a: usize) -> Result<impl Iterator<Item = usize> + '_, GraphError> {
+        if !self.contains_node(a) {
+            return Err(GraphError::NodeNotFound(a));
+        }
+
+        Ok((0..self.heads.len()).filter(move |&e| {
+            self.contains_hyperedge(e) && self.heads[e].binary_search(&a).is_ok()
+        }))
+    }
+}
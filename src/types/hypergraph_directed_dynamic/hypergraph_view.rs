@@ -0,0 +1,73 @@
+use crate::{DynamicDirectedHypergraph, HypergraphView};
+
+impl<N, E> HypergraphView<N, E> for DynamicDirectedHypergraph<N, E> {
+    /// Checks if the hypergraph is in a frozen, high-performance state.
+    /// For `DynamicDirectedHypergraph`, this is by definition always false.
+    fn is_frozen(&self) -> bool {
+        false
+    }
+
+    /// Checks if a valid, non-tombstoned node exists at the given index.
+    fn contains_node(&self, index: usize) -> bool {
+        self.nodes
+            .get(index)
+            .is_some_and(|node_opt| node_opt.is_some())
+    }
+
+    /// Retrieves a reference to the payload of a node, if it exists and has not been removed.
+    fn get_node(&self, index: usize) -> Option<&N> {
+        self.nodes.get(index).and_then(|node_opt| node_opt.as_ref())
+    }
+
+    /// Returns the total number of valid, non-tombstoned nodes.
+    ///
+    /// Note: This is an O(V) operation as it must skip removed nodes.
+    fn number_nodes(&self) -> usize {
+        self.nodes.iter().filter(|n| n.is_some()).count()
+    }
+
+    /// Checks if a valid, non-tombstoned hyperedge exists at the given index.
+    fn contains_hyperedge(&self, index: usize) -> bool {
+        self.hyperedges
+            .get(index)
+            .is_some_and(|edge_opt| edge_opt.is_some())
+    }
+
+    /// Retrieves a reference to the payload of a hyperedge, if it exists and has not been removed.
+    fn get_hyperedge(&self, index: usize) -> Option<&E> {
+        self.hyperedges
+            .get(index)
+            .and_then(|edge_opt| edge_opt.as_ref())
+    }
+
+    /// Returns the total number of valid, non-tombstoned hyperedges.
+    ///
+    /// Note: This is an O(H) operation as it must skip removed hyperedges.
+    fn number_hyperedges(&self) -> usize {
+        self.hyperedges.iter().filter(|e| e.is_some()).count()
+    }
+
+    /// Checks if the live `node` is in the tail or the head of the live `hyperedge`.
+    fn is_incident(&self, node: usize, hyperedge: usize) -> bool {
+        self.contains_hyperedge(hyperedge)
+            && self.contains_node(node)
+            && (self.tails[hyperedge].binary_search(&node).is_ok()
+                || self.heads[hyperedge].binary_search(&node).is_ok())
+    }
+
+    /// Returns the total number of live tail and head incidences.
+    ///
+    /// Note: This is an O(H + I) operation, where I is the number of stored incidences.
+    fn number_incidences(&self) -> usize {
+        (0..self.hyperedges.len())
+            .filter(|&e| self.contains_hyperedge(e))
+            .map(|e| {
+                self.tails[e]
+                    .iter()
+                    .chain(&self.heads[e])
+                    .filter(|&&n| self.contains_node(n))
+                    .count()
+            })
+            .sum()
+    }
+}
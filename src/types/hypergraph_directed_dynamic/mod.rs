@@ -0,0 +1,74 @@
+mod hypergraph_freeze;
+mod hypergraph_mut;
+mod hypergraph_traversal;
+mod hypergraph_view;
+
+/// A mutable hypergraph of directed hyperedges, optimized for building and evolution.
+///
+/// This is the dynamic counterpart of `DirectedHypergraph`. Each hyperedge stores
+/// sorted tail and head node lists. Nodes and hyperedges are removed by
+/// "tombstoning", which keeps all existing indices stable until the hypergraph
+/// is `freeze`n.
+pub struct DynamicDirectedHypergraph<N, E> {
+    // Node payloads, indexed directly by `usize`.
+    // The use of `Option` allows for efficient O(1) node removal ("tombstoning").
+    nodes: Vec<Option<N>>,
+
+    // Hyperedge payloads, indexed directly by `usize`. Tombstoned like `nodes`.
+    hyperedges: Vec<Option<E>>,
+
+    // Sorted, deduplicated tail (source) node list of each hyperedge.
+    tails: Vec<Vec<usize>>,
+
+    // Sorted, deduplicated head (target) node list of each hyperedge.
+    heads: Vec<Vec<usize>>,
+}
+
+impl<N, E> DynamicDirectedHypergraph<N, E> {
+    /// Creates a new, empty `DynamicDirectedHypergraph`.
+    pub fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            hyperedges: Vec::new(),
+            tails: Vec::new(),
+            heads: Vec::new(),
+        }
+    }
+
+    /// Creates a new, empty `DynamicDirectedHypergraph` with pre-allocated capacity.
+    ///
+    /// # Arguments
+    /// * `num_nodes`: The number of nodes to pre-allocate space for.
+    /// * `num_hyperedges`: The number of hyperedges to pre-allocate space for.
+    pub fn with_capacity(num_nodes: usize, num_hyperedges: usize) -> Self {
+        Self {
+            nodes: Vec::with_capacity(num_nodes),
+            hyperedges: Vec::with_capacity(num_hyperedges),
+            tails: Vec::with_capacity(num_hyperedges),
+            heads: Vec::with_capacity(num_hyperedges),
+        }
+    }
+}
+
+impl<N, E> Default for DynamicDirectedHypergraph<N, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N, E> DynamicDirectedHypergraph<N, E> {
+    // Internal helper for unfreeze
+    pub(crate) fn construct(
+        nodes: Vec<Option<N>>,
+        hyperedges: Vec<Option<E>>,
+        tails: Vec<Vec<usize>>,
+        heads: Vec<Vec<usize>>,
+    ) -> Self {
+        Self {
+            nodes,
+            hyperedges,
+            tails,
+            heads,
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use crate::types::hypergraph_csm::build_incidence_csr;
+use crate::{DynamicHypergraph, Hypergraph, HypergraphFreezable};
+
+impl<N, E> HypergraphFreezable<N, E> for DynamicHypergraph<N, E> {
+    /// Consumes the dynamic hypergraph to create a static, high-performance `Hypergraph`.
+    ///
+    /// This is an O(V + H + I) operation, where I is the number of incidences. It:
+    ///   1. Removes tombstoned nodes and hyperedges and re-indexes both partitions.
+    ///   2. Drops memberships of removed nodes from every hyperedge.
+    ///   3. Builds the hyperedge-to-node CSR and its node-to-hyperedge transpose.
+    ///
+    /// Like `DynamicGraph::freeze`, this method is infallible. The member lists are
+    /// always sorted and in bounds, and the re-indexing is monotone, so every list
+    /// stays sorted after remapping. Payloads are moved, not cloned.
+    fn freeze(self) -> Hypergraph<N, E> {
+        let Self {
+            nodes: old_nodes,
+            hyperedges: old_hyperedges,
+            members: old_members,
+        } = self;
+
+        // --- Compact the node partition ---
+        let mut compacted_nodes = Vec::with_capacity(old_nodes.len());
+        let mut node_remap = vec![None; old_nodes.len()];
+        for (old_index, node_opt) in old_nodes.into_iter().enumerate() {
+            if let Some(node) = node_opt {
+                node_remap[old_index] = Some(compacted_nodes.len());
+                compacted_nodes.push(node);
+            }
+        }
+        compacted_nodes.shrink_to_fit();
+
+        // --- Compact the hyperedge partition and remap members ---
+        let mut compacted_hyperedges = Vec::with_capacity(old_hyperedges.len());
+        let mut rows: Vec<Vec<usize>> = Vec::with_capacity(old_hyperedges.len());
+        for (edge_opt, member_list) in old_hyperedges.into_iter().zip(old_members) {
+            if let Some(payload) = edge_opt {
+                compacted_hyperedges.push(payload);
+                rows.push(
+                    member_list
+                        .into_iter()
+                        .filter_map(|old_node| node_remap[old_node])
+                        .collect(),
+                );
+            }
+        }
+        compacted_hyperedges.shrink_to_fit();
+
+        // --- Build both incidence structures ---
+        let (hyperedge_incidence, node_incidence) =
+            build_incidence_csr(&rows, compacted_nodes.len());
+
+        Hypergraph::construct(
+            compacted_nodes,
+            compacted_hyperedges,
+            hyperedge_incidence,
+            node_incidence,
+        )
+    }
+}
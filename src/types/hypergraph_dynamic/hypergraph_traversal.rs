@@ -0,0 +1,40 @@
+use crate::{DynamicHypergraph, GraphError, HypergraphTraversal, HypergraphView};
+
+impl<N, E> HypergraphTraversal<N, E> for DynamicHypergraph<N, E> {
+    /// Returns an iterator over the live member nodes of hyperedge `e`.
+    ///
+    /// # Errors
+    /// Returns `GraphError::HyperedgeNotFound` if the hyperedge does not exist or has been removed.
+    fn hyperedge_nodes(&self, e: usize) -> Result<impl Iterator<Item = usize> + '_, GraphError> {
+        if !self.contains_hyperedge(e) {
+            return Err(GraphError::HyperedgeNotFound(e));
+        }
+
+        // Filter out tombstoned members during iteration.
+        Ok(self.members[e]
+            .iter()
+            .copied()
+            .filter(|&n| self.contains_node(n)))
+    }
+
+    /// Returns an iterator over the live hyperedges that node `a` is a member of.
+    ///
+    /// The dynamic hypergraph does not maintain a node-to-hyperedge index, so this
+    /// scans every hyperedge's member list with a binary search. Freeze the
+    /// hypergraph for O(1) access to the incident hyperedges.
+    ///
+    /// # Errors
+    /// Returns `GraphError::NodeNotFound` if the node does not exist or has been removed.
+    fn node_hyperedges(&self, a: usize) -> Result<impl Iterator<Item = usize> + '_, GraphError> {
+        if !self.contains_node(a) {
+            return Err(GraphError::NodeNotFound(a));
+        }
+
+        Ok(self
+            .members
+            .iter()
+            .enumerate()
+            .filter(move |(e, list)| self.contains_hyperedge(*e) && list.binary_search(&a).is_ok())
+            .map(|(e, _)| e))
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{DynamicHypergraph, HypergraphView};
+
+impl<N, E> HypergraphView<N, E> for DynamicHypergraph<N, E> {
+    /// Checks if the hypergraph is in a frozen, high-performance state.
+    /// For `DynamicHypergraph`, this is by definition always false.
+    fn is_frozen(&self) -> bool {
+        false
+    }
+
+    /// Checks if a valid, non-tombstoned node exists at the given index.
+    fn contains_node(&self, index: usize) -> bool {
+        self.nodes
+            .get(index)
+            .is_some_and(|node_opt| node_opt.is_some())
+    }
+
+    /// Retrieves a reference to the payload of a node, if it exists and has not been removed.
+    fn get_node(&self, index: usize) -> Option<&N> {
+        self.nodes.get(index).and_then(|node_opt| node_opt.as_ref())
+    }
+
+    /// Returns the total number of valid, non-tombstoned nodes.
+    ///
+    /// Note: This is an O(V) operation as it must skip removed nodes.
+    fn number_nodes(&self) -> usize {
+        self.nodes.iter().filter(|n| n.is_some()).count()
+    }
+
+    /// Checks if a valid, non-tombstoned hyperedge exists at the given index.
+    fn contains_hyperedge(&self, index: usize) -> bool {
+        self.hyperedges
+            .get(index)
+            .is_some_and(|edge_opt| edge_opt.is_some())
+    }
+
+    /// Retrieves a reference to the payload of a hyperedge, if it exists and has not been removed.
+    fn get_hyperedge(&self, index: usize) -> Option<&E> {
+        self.hyperedges
+            .get(index)
+            .and_then(|edge_opt| edge_opt.as_ref())
+    }
+
+    /// Returns the total number of valid, non-tombstoned hyperedges.
+    ///
+    /// Note: This is an O(H) operation as it must skip removed hyperedges.
+    fn number_hyperedges(&self) -> usize {
+        self.hyperedges.iter().filter(|e| e.is_some()).count()
+    }
+
+    /// Checks if `node` is a live member of the live hyperedge `hyperedge`.
+    fn is_incident(&self, node: usize, hyperedge: usize) -> bool {
+        self.contains_hyperedge(hyperedge)
+            && self.contains_node(node)
+            && self.members[hyperedge].binary_search(&node).is_ok()
+    }
+
+    /// Returns the total number of node-hyperedge incidences.
+    ///
+    /// Note: This is an O(H + I) operation, where I is the number of stored incidences,
+    /// as it must skip removed hyperedges and removed member nodes.
+    fn number_incidences(&self) -> usize {
+        self.members
+            .iter()
+            .enumerate()
+            .filter(|(e, _)| self.contains_hyperedge(*e))
+            .map(|(_, list)| list.iter().filter(|&&n| self.contains_node(n)).count())
+            .sum()
+    }
+}
@@ -0,0 +1,79 @@
+mod hypergraph_freeze;
+mod hypergraph_mut;
+mod hypergraph_traversal;
+mod hypergraph_view;
+
+/// A mutable hypergraph optimized for building and evolution.
+///
+/// This is the dynamic counterpart of `Hypergraph`. Each hyperedge stores a
+/// sorted list of its member nodes. Both nodes and hyperedges are removed by
+/// "tombstoning", which keeps all existing indices stable until the hypergraph
+/// is `freeze`n.
+pub struct DynamicHypergraph<N, E> {
+    // Node payloads, indexed directly by `usize`.
+    // The use of `Option` allows for efficient O(1) node removal ("tombstoning")
+    // without invalidating other node indices.
+    nodes: Vec<Option<N>>,
+
+    // Hyperedge payloads, indexed directly by `usize`. Tombstoned like `nodes`.
+    hyperedges: Vec<Option<E>>,
+
+    // Member lists: A vector where each index corresponds to a hyperedge,
+    // and the value is the sorted, deduplicated list of its member nodes.
+    members: Vec<Vec<usize>>,
+}
+
+impl<N, E> DynamicHypergraph<N, E> {
+    /// Creates a new, empty `DynamicHypergraph`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use next_graph::{DynamicHypergraph, HypergraphView};
+    ///
+    /// let graph = DynamicHypergraph::<String, u32>::new();
+    /// assert_eq!(graph.number_nodes(), 0);
+    /// assert_eq!(graph.number_hyperedges(), 0);
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            hyperedges: Vec::new(),
+            members: Vec::new(),
+        }
+    }
+
+    /// Creates a new, empty `DynamicHypergraph` with pre-allocated capacity.
+    ///
+    /// # Arguments
+    /// * `num_nodes`: The number of nodes to pre-allocate space for.
+    /// * `num_hyperedges`: The number of hyperedges to pre-allocate space for.
+    pub fn with_capacity(num_nodes: usize, num_hyperedges: usize) -> Self {
+        Self {
+            nodes: Vec::with_capacity(num_nodes),
+            hyperedges: Vec::with_capacity(num_hyperedges),
+            members: Vec::with_capacity(num_hyperedges),
+        }
+    }
+}
+
+impl<N, E> Default for DynamicHypergraph<N, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N, E> DynamicHypergraph<N, E> {
+    // Internal helper for unfreeze
+    pub(crate) fn construct(
+        nodes: Vec<Option<N>>,
+        hyperedges: Vec<Option<E>>,
+        members: Vec<Vec<usize>>,
+    ) -> Self {
+        Self {
+            nodes,
+            hyperedges,
+            members,
+        }
+    }
+}
@@ -1,2 +1,11 @@
+pub mod dominator_tree;
 pub mod graph_csm;
 pub mod graph_dynamic;
+pub mod hypergraph_csm;
+pub mod hypergraph_directed_csm;
+pub mod hypergraph_directed_dynamic;
+pub mod hypergraph_dynamic;
+pub mod reachability_index;
+pub mod transitive_closure;
+pub mod traversal;
+pub mod traversal_workspace;
@@ -0,0 +1,219 @@
+use crate::types::graph_csm::CsrAdjacency;
+use crate::{CsmGraph, GraphTraversal, GraphView, StructuralGraphAlgorithms};
+use std::collections::HashSet;
+
+// The default number of interval labels per node.
+const DEFAULT_NUM_LABELS: usize = 3;
+
+/// A prebuilt index for answering many reachability queries against one `CsmGraph`.
+///
+/// The index follows the GRAIL scheme (Yildirim, Chaoji and Zaki). The graph is first
+/// condensed into a DAG of strongly connected components. Every component then gets
+/// `k` interval labels `[low, rank]`, one per randomized post-order DFS, such that the
+/// interval of a reachable component is always nested in the interval of its source.
+///
+/// A query `is_reachable(a, b)` is then answered in three steps:
+///
+/// 1. **Same Component:** If `a` and `b` share an SCC, `b` is reachable.
+/// 2. **Negative Cuts:** Component ids are topologically ordered, so `b` is unreachable
+///    if its id is smaller. It is also unreachable if any interval of `b` is not nested
+///    in the corresponding interval of `a`. Most negative queries end here in O(k).
+/// 3. **Guided DFS:** Otherwise, a DFS over the condensation only descends into
+///    components whose intervals still contain the target's.
+///
+/// The index is immutable and can be shared across threads.
+pub struct ReachabilityIndex {
+    // The condensation component of every original node.
+    components: Vec<usize>,
+    // Forward adjacency of the condensation DAG.
+    dag: CsrAdjacency<()>,
+    // Number of interval labels per component.
+    num_labels: usize,
+    // `(low, rank)` intervals, `num_labels` consecutive entries per component.
+    intervals: Vec<(usize, usize)>,
+}
+
+impl ReachabilityIndex {
+    /// Builds a reachability index for the given graph with the default number of labels.
+    ///
+    /// The graph may contain cycles.
+    pub fn new<N, W>(graph: &CsmGraph<N, W>) -> Self
+    where
+        W: Default,
+    {
+        Self::with_num_labels(graph, DEFAULT_NUM_LABELS)
+    }
+
+    /// Builds a reachability index with `num_labels` interval labels per component.
+    ///
+    /// More labels prune more negative queries at the cost of O(V * num_labels) memory.
+    /// A value of `0` is treated as `1`.
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** O((V + E) * num_labels), including the condensation.
+    /// - **Space Complexity:** O(V * num_labels + E).
+    pub fn with_num_labels<N, W>(graph: &CsmGraph<N, W>, num_labels: usize) -> Self
+    where
+        W: Default,
+    {
+        let num_labels = num_labels.max(1);
+        let condensed = graph.condensation();
+        let num_components = condensed.number_nodes();
+
+        let mut components = vec![0; graph.number_nodes()];
+        let mut offsets = Vec::with_capacity(num_components + 1);
+        let mut targets = Vec::with_capacity(condensed.number_edges());
+        offsets.push(0);
+        for c in 0..num_components {
+            // The unwraps are safe because we are dealing with valid node indices.
+            for &member in condensed.get_node(c).unwrap() {
+                components[member] = c;
+            }
+            targets.extend(condensed.outbound_edges(c).unwrap());
+            offsets.push(targets.len());
+        }
+        let num_edges = targets.len();
+        let dag = CsrAdjacency {
+            offsets,
+            targets,
+            weights: vec![(); num_edges],
+        };
+
+        let mut intervals = vec![(0, 0); num_components * num_labels];
+        for label in 0..num_labels {
+            Self::assign_intervals(&dag, label, num_labels, &mut intervals);
+        }
+
+        Self {
+            components,
+            dag,
+            num_labels,
+            intervals,
+        }
+    }
+
+    /// Computes one interval label for every component with a randomized post-order DFS.
+    ///
+    /// `rank` is the post-order number of a component and `low` is the smallest rank
+    /// in its reachable set, so every reachable component has a nested interval.
+    fn assign_intervals(
+        dag: &CsrAdjacency<()>,
+        label: usize,
+        num_labels: usize,
+        intervals: &mut [(usize, usize)],
+    ) {
+        const UNVISITED: usize = usize::MAX;
+
+        let num_components = dag.offsets.len() - 1;
+        let degree = |c: usize| dag.offsets[c + 1] - dag.offsets[c];
+        // A cheap per-label hash decides where each child scan starts, which
+        // randomizes the traversal order without shuffling the adjacency.
+        let rotation = |c: usize| mix(c as u64 ^ ((label as u64) << 32)) as usize;
+
+        let mut low = vec![UNVISITED; num_components];
+        let mut counter = 0;
+
+        let root_shift = rotation(num_components) % num_components.max(1);
+        for i in 0..num_components {
+            let root = (i + root_shift) % num_components;
+            if low[root] != UNVISITED {
+                continue;
+            }
+
+            // Each frame holds a component and the number of children scanned so far.
+            // `low` starts at the next rank to be assigned, which bounds its subtree.
+            low[root] = counter;
+            let mut stack = vec![(root, 0)];
+            while let Some(&mut (u, ref mut scanned)) = stack.last_mut() {
+                if *scanned < degree(u) {
+                    let index = dag.offsets[u] + (rotation(u) + *scanned) % degree(u);
+                    *scanned += 1;
+                    let v = dag.targets[index];
+                    if low[v] == UNVISITED {
+                        low[v] = counter;
+                        stack.push((v, 0));
+                    } else {
+                        low[u] = low[u].min(low[v]);
+                    }
+                } else {
+                    stack.pop();
+                    intervals[u * num_labels + label] = (low[u], counter);
+                    counter += 1;
+                    if let Some(&(parent, _)) = stack.last() {
+                        low[parent] = low[parent].min(low[u]);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Checks if the intervals of component `source` contain those of component `target`.
+    fn contains(&self, source: usize, target: usize) -> bool {
+        let k = self.num_labels;
+        self.intervals[source * k..(source + 1) * k]
+            .iter()
+            .zip(&self.intervals[target * k..(target + 1) * k])
+            .all(|(&(s_low, s_rank), &(t_low, t_rank))| s_low <= t_low && t_rank <= s_rank)
+    }
+}
+
+impl ReachabilityIndex {
+    /// Returns the number of nodes of the indexed graph.
+    pub fn number_nodes(&self) -> usize {
+        self.components.len()
+    }
+
+    /// Returns the number of strongly connected components of the indexed graph.
+    pub fn number_components(&self) -> usize {
+        self.dag.offsets.len() - 1
+    }
+
+    /// Checks if a path of any length exists from a start to a stop index.
+    ///
+    /// This mirrors `GraphAlgorithms::is_reachable`: every valid node reaches itself,
+    /// and `false` is returned if either index is out of bounds. Only the guided DFS
+    /// allocates, and only in proportion to the components it visits.
+    pub fn is_reachable(&self, start_index: usize, stop_index: usize) -> bool {
+        let (Some(&source), Some(&target)) = (
+            self.components.get(start_index),
+            self.components.get(stop_index),
+        ) else {
+            return false;
+        };
+
+        if source == target {
+            return true;
+        }
+        if source > target || !self.contains(source, target) {
+            return false;
+        }
+
+        let mut visited = HashSet::new();
+        let mut stack = vec![source];
+        visited.insert(source);
+        while let Some(u) = stack.pop() {
+            let start = self.dag.offsets[u];
+            let end = self.dag.offsets[u + 1];
+            for &v in &self.dag.targets[start..end] {
+                if v == target {
+                    return true;
+                }
+                // Only descend into components that can still reach the target.
+                if v < target && self.contains(v, target) && visited.insert(v) {
+                    stack.push(v);
+                }
+            }
+        }
+
+        false
+    }
+}
+
+/// A SplitMix64 finalizer, used as a cheap deterministic hash.
+fn mix(mut x: u64) -> u64 {
+    x = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    x ^ (x >> 31)
+}
@@ -0,0 +1,99 @@
+use crate::GraphError;
+
+/// A compact, precomputed transitive closure of a DAG.
+///
+/// The closure stores one bitset row per node, where bit `b` of row `a` is set if
+/// `b` is reachable from `a` by a path of at least one edge. Rows are packed into
+/// a single `Vec<u64>`, so the index needs V² / 8 bytes and answers reachability
+/// queries in O(1) with a single memory access.
+///
+/// A `TransitiveClosure` is built with `DagAlgorithms::transitive_closure`.
+pub struct TransitiveClosure {
+    num_nodes: usize,
+    // Number of `u64` words per row: ceil(num_nodes / 64).
+    words_per_row: usize,
+    // All rows, stored contiguously.
+    bits: Vec<u64>,
+}
+
+impl TransitiveClosure {
+    // Internal helper for the closure algorithm: a closure where nothing is reachable.
+    pub(crate) fn with_nodes(num_nodes: usize) -> Self {
+        let words_per_row = num_nodes.div_ceil(64);
+        Self {
+            num_nodes,
+            words_per_row,
+            bits: vec![0; num_nodes * words_per_row],
+        }
+    }
+
+    /// Marks `target` as reachable from `source`.
+    pub(crate) fn insert(&mut self, source: usize, target: usize) {
+        self.bits[source * self.words_per_row + target / 64] |= 1 << (target % 64);
+    }
+
+    /// Marks everything reachable from `source` as reachable from `target` as well.
+    pub(crate) fn union_row(&mut self, target: usize, source: usize) {
+        let w = self.words_per_row;
+        for i in 0..w {
+            self.bits[target * w + i] |= self.bits[source * w + i];
+        }
+    }
+
+    /// Returns the packed bitset row of `source`.
+    pub(crate) fn row(&self, source: usize) -> &[u64] {
+        let w = self.words_per_row;
+        &self.bits[source * w..(source + 1) * w]
+    }
+}
+
+impl TransitiveClosure {
+    /// Returns the number of nodes covered by the closure.
+    pub fn number_nodes(&self) -> usize {
+        self.num_nodes
+    }
+
+    /// Checks if a path of any length exists from a start to a stop index in O(1).
+    ///
+    /// This mirrors `GraphAlgorithms::is_reachable`: every valid node reaches itself,
+    /// and `false` is returned if either index is out of bounds.
+    pub fn is_reachable(&self, start_index: usize, stop_index: usize) -> bool {
+        if start_index >= self.num_nodes || stop_index >= self.num_nodes {
+            return false;
+        }
+        start_index == stop_index
+            || self.row(start_index)[stop_index / 64] & (1 << (stop_index % 64)) != 0
+    }
+
+    /// Returns an iterator over all nodes reachable from `start_index`, in increasing order.
+    ///
+    /// The start node itself is not included.
+    ///
+    /// # Errors
+    /// Returns `GraphError::NodeNotFound` if the index is out of bounds.
+    pub fn reachable_from(
+        &self,
+        start_index: usize,
+    ) -> Result<impl Iterator<Item = usize> + '_, GraphError> {
+        if start_index >= self.num_nodes {
+            return Err(GraphError::NodeNotFound(start_index));
+        }
+
+        Ok(self
+            .row(start_index)
+            .iter()
+            .enumerate()
+            .flat_map(|(word_index, &word)| {
+                // Yield the position of every set bit in this word.
+                let mut remaining = word;
+                std::iter::from_fn(move || {
+                    if remaining == 0 {
+                        return None;
+                    }
+                    let bit = remaining.trailing_zeros() as usize;
+                    remaining &= remaining - 1;
+                    Some(word_index * 64 + bit)
+                })
+            }))
+    }
+}
@@ -0,0 +1,81 @@
+use crate::GraphTraversal;
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+
+/// A lazy breadth-first traversal that yields node indices in visiting order.
+///
+/// The start node is yielded first, followed by its successors in the order of
+/// `outbound_edges`, then their successors, and so on. Each reachable node is
+/// yielded exactly once.
+///
+/// # Example
+/// ```
+/// use next_graph::{Bfs, DynamicGraph, Freezable, GraphMut};
+///
+/// let mut g = DynamicGraph::new();
+/// let a = g.add_node(());
+/// let b = g.add_node(());
+/// let c = g.add_node(());
+/// g.add_edge(a, b, ()).unwrap();
+/// g.add_edge(b, c, ()).unwrap();
+/// let graph = g.freeze();
+///
+/// let order: Vec<usize> = Bfs::new(&graph, a).collect();
+/// assert_eq!(order, vec![a, b, c]);
+/// ```
+pub struct Bfs<'a, G, N, W> {
+    graph: &'a G,
+    queue: VecDeque<usize>,
+    // Grown lazily to the highest discovered node index.
+    discovered: Vec<bool>,
+    _marker: PhantomData<fn() -> (N, W)>,
+}
+
+impl<'a, G, N, W> Bfs<'a, G, N, W>
+where
+    G: GraphTraversal<N, W>,
+{
+    /// Creates a new breadth-first traversal starting at `start`.
+    ///
+    /// If `start` is not a valid node, the traversal is empty.
+    pub fn new(graph: &'a G, start: usize) -> Self {
+        let mut bfs = Self {
+            graph,
+            queue: VecDeque::new(),
+            discovered: Vec::new(),
+            _marker: PhantomData,
+        };
+        if graph.contains_node(start) {
+            bfs.discover(start);
+        }
+        bfs
+    }
+
+    /// Marks `node` as discovered and enqueues it, unless it was already discovered.
+    fn discover(&mut self, node: usize) {
+        if self.discovered.len() <= node {
+            self.discovered.resize(node + 1, false);
+        }
+        if !self.discovered[node] {
+            self.discovered[node] = true;
+            self.queue.push_back(node);
+        }
+    }
+}
+
+impl<G, N, W> Iterator for Bfs<'_, G, N, W>
+where
+    G: GraphTraversal<N, W>,
+{
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let u = self.queue.pop_front()?;
+        if let Ok(successors) = self.graph.outbound_edges(u) {
+            for v in successors {
+                self.discover(v);
+            }
+        }
+        Some(u)
+    }
+}
@@ -0,0 +1,255 @@
+use crate::GraphTraversal;
+use std::marker::PhantomData;
+use std::ops::ControlFlow;
+
+/// An event emitted by a depth-first traversal.
+///
+/// For every node, `Discover` is emitted when the traversal first reaches it and
+/// `Finish` once all of its outgoing edges have been explored. Every edge explored in
+/// between is classified by the state of its target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DfsEvent {
+    /// A node is reached for the first time.
+    Discover(usize),
+    /// The edge `(source, target)` leads to an undiscovered node, which is discovered next.
+    TreeEdge(usize, usize),
+    /// The edge `(source, target)` leads to a node that is still being explored,
+    /// i.e. an ancestor on the current path. Every back edge closes a cycle.
+    BackEdge(usize, usize),
+    /// The edge `(source, target)` leads to a node that is already finished. This covers
+    /// both cross edges and forward edges to a descendant.
+    CrossEdge(usize, usize),
+    /// All outgoing edges of a node have been explored.
+    Finish(usize),
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum VisitState {
+    Undiscovered,
+    InProgress,
+    Finished,
+}
+
+/// A lazy depth-first traversal that yields `DfsEvent`s.
+///
+/// This is the building block for custom depth-first analyses: consume the events
+/// with a `for` loop, stop early with `break`, or use `depth_first_search` with a
+/// visitor closure. The traversal is iterative, so it cannot overflow the call stack.
+///
+/// The successors of every node on the current path are buffered in a single shared
+/// vector, so memory is bounded by the sum of the out-degrees along the path.
+///
+/// # Example
+/// ```
+/// use next_graph::{DfsEvent, DfsEvents, DynamicGraph, Freezable, GraphMut};
+///
+/// let mut g = DynamicGraph::new();
+/// let a = g.add_node(());
+/// let b = g.add_node(());
+/// g.add_edge(a, b, ()).unwrap();
+/// g.add_edge(b, a, ()).unwrap();
+/// let graph = g.freeze();
+///
+/// let has_cycle = DfsEvents::new(&graph, [a])
+///     .any(|event| matches!(event, DfsEvent::BackEdge(..)));
+/// assert!(has_cycle);
+/// ```
+pub struct DfsEvents<'a, G, N, W, S> {
+    graph: &'a G,
+    // Consumed lazily, so a range over all nodes costs no memory.
+    starts: S,
+    // Grown lazily to the highest discovered node index.
+    states: Vec<VisitState>,
+    // Frames of `(node, start of its successors, next successor position)`.
+    // The successors of the topmost frame always form the tail of `successors`.
+    frames: Vec<(usize, usize, usize)>,
+    successors: Vec<usize>,
+    // A node reached by a tree edge whose `Discover` event is emitted next.
+    pending: Option<usize>,
+    _marker: PhantomData<fn() -> (N, W)>,
+}
+
+impl<'a, G, N, W, S> DfsEvents<'a, G, N, W, S>
+where
+    G: GraphTraversal<N, W>,
+    S: Iterator<Item = usize>,
+{
+    /// Creates a new depth-first traversal that explores from each start node in order.
+    ///
+    /// Start nodes that are invalid or already discovered by an earlier start are skipped.
+    /// Pass `0..graph.number_nodes()` to traverse the whole graph.
+    pub fn new<I>(graph: &'a G, starts: I) -> Self
+    where
+        I: IntoIterator<IntoIter = S>,
+    {
+        Self {
+            graph,
+            starts: starts.into_iter(),
+            states: Vec::new(),
+            frames: Vec::new(),
+            successors: Vec::new(),
+            pending: None,
+            _marker: PhantomData,
+        }
+    }
+
+    fn state(&self, node: usize) -> VisitState {
+        self.states
+            .get(node)
+            .copied()
+            .unwrap_or(VisitState::Undiscovered)
+    }
+
+    fn set_state(&mut self, node: usize, state: VisitState) {
+        if self.states.len() <= node {
+            self.states.resize(node + 1, VisitState::Undiscovered);
+        }
+        self.states[node] = state;
+    }
+
+    /// Pushes a frame for a newly discovered node and buffers its successors.
+    fn enter(&mut self, node: usize) {
+        let start = self.successors.len();
+        if let Ok(successors) = self.graph.outbound_edges(node) {
+            self.successors.extend(successors);
+        }
+        self.frames.push((node, start, start));
+    }
+}
+
+impl<G, N, W, S> Iterator for DfsEvents<'_, G, N, W, S>
+where
+    G: GraphTraversal<N, W>,
+    S: Iterator<Item = usize>,
+{
+    type Item = DfsEvent;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(v) = self.pending.take() {
+            self.enter(v);
+            return Some(DfsEvent::Discover(v));
+        }
+
+        if let Some(&mut (u, start, ref mut position)) = self.frames.last_mut() {
+            if *position < self.successors.len() {
+                let v = self.successors[*position];
+                *position += 1;
+
+                return Some(match self.state(v) {
+                    VisitState::Undiscovered => {
+                        self.set_state(v, VisitState::InProgress);
+                        self.pending = Some(v);
+                        DfsEvent::TreeEdge(u, v)
+                    }
+                    VisitState::InProgress => DfsEvent::BackEdge(u, v),
+                    VisitState::Finished => DfsEvent::CrossEdge(u, v),
+                });
+            }
+
+            // All edges of `u` are explored.
+            self.frames.pop();
+            self.successors.truncate(start);
+            self.set_state(u, VisitState::Finished);
+            return Some(DfsEvent::Finish(u));
+        }
+
+        // The current tree is complete: continue with the next undiscovered start node.
+        while let Some(s) = self.starts.next() {
+            if self.graph.contains_node(s) && self.state(s) == VisitState::Undiscovered {
+                self.set_state(s, VisitState::InProgress);
+                self.enter(s);
+                return Some(DfsEvent::Discover(s));
+            }
+        }
+
+        None
+    }
+}
+
+/// A lazy depth-first traversal that yields node indices in discovery (pre-)order.
+///
+/// # Example
+/// ```
+/// use next_graph::{Dfs, DynamicGraph, Freezable, GraphMut};
+///
+/// let mut g = DynamicGraph::new();
+/// let a = g.add_node(());
+/// let b = g.add_node(());
+/// let c = g.add_node(());
+/// g.add_edge(a, b, ()).unwrap();
+/// g.add_edge(a, c, ()).unwrap();
+/// let graph = g.freeze();
+///
+/// let order: Vec<usize> = Dfs::new(&graph, a).collect();
+/// assert_eq!(order, vec![a, b, c]);
+/// ```
+pub struct Dfs<'a, G, N, W> {
+    events: DfsEvents<'a, G, N, W, std::array::IntoIter<usize, 1>>,
+}
+
+impl<'a, G, N, W> Dfs<'a, G, N, W>
+where
+    G: GraphTraversal<N, W>,
+{
+    /// Creates a new depth-first traversal starting at `start`.
+    ///
+    /// If `start` is not a valid node, the traversal is empty.
+    pub fn new(graph: &'a G, start: usize) -> Self {
+        Self {
+            events: DfsEvents::new(graph, [start]),
+        }
+    }
+}
+
+impl<G, N, W> Iterator for Dfs<'_, G, N, W>
+where
+    G: GraphTraversal<N, W>,
+{
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.events.find_map(|event| match event {
+            DfsEvent::Discover(node) => Some(node),
+            _ => None,
+        })
+    }
+}
+
+/// Runs a depth-first search from each start node and reports every event to `visitor`.
+///
+/// The visitor can stop the search early by returning `ControlFlow::Break`, whose value
+/// is then returned. Otherwise the search runs to completion and returns
+/// `ControlFlow::Continue(())`.
+///
+/// # Example
+/// ```
+/// use next_graph::{DfsEvent, DynamicGraph, Freezable, GraphMut, GraphView, depth_first_search};
+/// use std::ops::ControlFlow;
+///
+/// let mut g = DynamicGraph::new();
+/// let a = g.add_node(());
+/// let b = g.add_node(());
+/// g.add_edge(a, b, ()).unwrap();
+/// g.add_edge(b, a, ()).unwrap();
+/// let graph = g.freeze();
+///
+/// // Find the first back edge, which closes a cycle.
+/// let result = depth_first_search(&graph, 0..graph.number_nodes(), |event| match event {
+///     DfsEvent::BackEdge(u, v) => ControlFlow::Break((u, v)),
+///     _ => ControlFlow::Continue(()),
+/// });
+/// assert_eq!(result, ControlFlow::Break((b, a)));
+/// ```
+pub fn depth_first_search<G, N, W, B>(
+    graph: &G,
+    starts: impl IntoIterator<Item = usize>,
+    mut visitor: impl FnMut(DfsEvent) -> ControlFlow<B>,
+) -> ControlFlow<B>
+where
+    G: GraphTraversal<N, W>,
+{
+    for event in DfsEvents::new(graph, starts) {
+        visitor(event)?;
+    }
+    ControlFlow::Continue(())
+}
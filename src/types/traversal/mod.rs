@@ -0,0 +1,8 @@
+mod bfs;
+mod dfs;
+
+// Lazy traversals over any `GraphTraversal` implementor. They only use `outbound_edges`
+// and grow their visited bookkeeping lazily, so they need no node count up front.
+
+pub use bfs::Bfs;
+pub use dfs::{Dfs, DfsEvent, DfsEvents, depth_first_search};
@@ -0,0 +1,128 @@
+use std::collections::VecDeque;
+
+// Marks a node without a predecessor in the current traversal.
+pub(crate) const NO_PREDECESSOR: usize = usize::MAX;
+
+/// Reusable scratch buffers for graph traversals.
+///
+/// Algorithms like `shortest_path` or `find_cycle` need a `visited` set, a
+/// `predecessors` array and a queue or stack sized to the graph. A `TraversalWorkspace`
+/// keeps these buffers between calls, so a loop that issues many queries against the
+/// same graph only allocates once.
+///
+/// Instead of clearing the buffers after each traversal, the workspace uses epoch
+/// stamping: every traversal starts a new epoch, and a node counts as visited only if
+/// its stamp equals the current epoch. Starting a traversal is therefore O(1), no
+/// matter how many nodes the previous traversal touched.
+///
+/// The workspace grows automatically to fit the largest graph it is used with. It can
+/// be reused across different graphs, but not shared between threads at the same time.
+///
+/// # Example
+/// ```
+/// use next_graph::{DynamicGraph, Freezable, GraphMut, TraversalWorkspace};
+///
+/// let mut g = DynamicGraph::new();
+/// let a = g.add_node(());
+/// let b = g.add_node(());
+/// g.add_edge(a, b, ()).unwrap();
+/// let graph = g.freeze();
+///
+/// let mut workspace = TraversalWorkspace::new();
+/// for _ in 0..1_000 {
+///     assert_eq!(graph.shortest_path_len_with_workspace(a, b, &mut workspace), Some(2));
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct TraversalWorkspace {
+    // The current traversal. Stamps equal to it are valid, all others are stale.
+    epoch: u32,
+    // The epoch in which each node was first visited.
+    visited: Vec<u32>,
+    // The epoch in which each node was fully explored (used by DFS-based algorithms).
+    finished: Vec<u32>,
+    // The predecessor of each visited node, or `NO_PREDECESSOR`.
+    // Entries are only meaningful for nodes visited in the current epoch.
+    predecessors: Vec<usize>,
+    // BFS queue of `(node, path length)` pairs.
+    pub(crate) queue: VecDeque<(usize, usize)>,
+    // DFS stack of `(node, edge cursor)` frames.
+    pub(crate) stack: Vec<(usize, usize)>,
+    // Buffered successors of the nodes on the DFS stack, for graphs without CSR cursors.
+    pub(crate) successors: Vec<usize>,
+}
+
+impl TraversalWorkspace {
+    /// Creates a new, empty `TraversalWorkspace`.
+    ///
+    /// The buffers are allocated on first use.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new `TraversalWorkspace` with buffers pre-allocated for `num_nodes` nodes.
+    pub fn with_capacity(num_nodes: usize) -> Self {
+        let mut workspace = Self::new();
+        workspace.grow(num_nodes);
+        workspace
+    }
+
+    /// Returns the number of nodes the workspace can currently hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.visited.len()
+    }
+}
+
+impl TraversalWorkspace {
+    /// Starts a new traversal over a graph with `num_nodes` nodes.
+    ///
+    /// This invalidates all stamps of the previous traversal in O(1) and clears the
+    /// queue, stack and successor buffer, which only hold the nodes the previous traversal touched.
+    pub(crate) fn begin(&mut self, num_nodes: usize) {
+        self.grow(num_nodes);
+        self.queue.clear();
+        self.stack.clear();
+        self.successors.clear();
+
+        if self.epoch == u32::MAX {
+            // The epoch wraps around: this is the only case that needs a full reset.
+            self.visited.fill(0);
+            self.finished.fill(0);
+            self.epoch = 0;
+        }
+        self.epoch += 1;
+    }
+
+    fn grow(&mut self, num_nodes: usize) {
+        if self.visited.len() < num_nodes {
+            self.visited.resize(num_nodes, 0);
+            self.finished.resize(num_nodes, 0);
+            self.predecessors.resize(num_nodes, NO_PREDECESSOR);
+        }
+    }
+
+    /// Marks `node` as visited with the given predecessor.
+    pub(crate) fn visit(&mut self, node: usize, predecessor: usize) {
+        self.visited[node] = self.epoch;
+        self.predecessors[node] = predecessor;
+    }
+
+    pub(crate) fn is_visited(&self, node: usize) -> bool {
+        self.visited[node] == self.epoch
+    }
+
+    /// Marks `node` as fully explored.
+    pub(crate) fn finish(&mut self, node: usize) {
+        self.finished[node] = self.epoch;
+    }
+
+    pub(crate) fn is_finished(&self, node: usize) -> bool {
+        self.finished[node] == self.epoch
+    }
+
+    /// Returns the predecessor of a node visited in the current traversal.
+    pub(crate) fn predecessor(&self, node: usize) -> Option<usize> {
+        let predecessor = self.predecessors[node];
+        (predecessor != NO_PREDECESSOR).then_some(predecessor)
+    }
+}
@@ -1,4 +1,8 @@
-use crate::{CsmGraph, DynamicGraph, Freezable, GraphMut};
+use crate::{
+    CsmGraph, DirectedHypergraph, DirectedHypergraphFreezable, DirectedHypergraphMut,
+    DynamicDirectedHypergraph, DynamicGraph, DynamicHypergraph, Freezable, GraphMut, Hypergraph,
+    HypergraphFreezable, HypergraphMut,
+};
 
 // Helper function to create a CsmGraph from a DynamicGraph
 pub fn create_csm_graph() -> CsmGraph<String, u32> {
@@ -17,3 +21,38 @@ pub fn create_csm_graph() -> CsmGraph<String, u32> {
 
     dynamic_graph.freeze()
 }
+
+// Helper function to create a Hypergraph from a DynamicHypergraph
+pub fn create_hypergraph() -> Hypergraph<String, u32> {
+    let mut dynamic_graph = DynamicHypergraph::new();
+    let n0 = dynamic_graph.add_node("A".to_string());
+    let n1 = dynamic_graph.add_node("B".to_string());
+    let n2 = dynamic_graph.add_node("C".to_string());
+    let n3 = dynamic_graph.add_node("D".to_string());
+    let n4 = dynamic_graph.add_node("E".to_string());
+
+    dynamic_graph.add_hyperedge(&[n0, n1, n2], 10).unwrap();
+    dynamic_graph.add_hyperedge(&[n1, n2, n3], 20).unwrap();
+    dynamic_graph.add_hyperedge(&[n3, n4], 30).unwrap();
+
+    dynamic_graph.freeze()
+}
+
+// Helper function to create a DirectedHypergraph from a DynamicDirectedHypergraph
+pub fn create_directed_hypergraph() -> DirectedHypergraph<String, u32> {
+    let mut dynamic_graph = DynamicDirectedHypergraph::new();
+    let n0 = dynamic_graph.add_node("A".to_string());
+    let n1 = dynamic_graph.add_node("B".to_string());
+    let n2 = dynamic_graph.add_node("C".to_string());
+    let n3 = dynamic_graph.add_node("D".to_string());
+    let n4 = dynamic_graph.add_node("E".to_string());
+
+    // A and B jointly cause C and D.
+    dynamic_graph
+        .add_hyperedge(&[n0, n1], &[n2, n3], 10)
+        .unwrap();
+    dynamic_graph.add_hyperedge(&[n2], &[n4], 20).unwrap();
+    dynamic_graph.add_hyperedge(&[n3, n4], &[n0], 30).unwrap();
+
+    dynamic_graph.freeze()
+}
@@ -0,0 +1,106 @@
+use next_graph::{GraphAlgorithms, GraphError, GraphTraversal, GraphView, algo};
+
+/// A minimal custom graph storage that only implements `GraphView` and `GraphTraversal`.
+///
+/// Removed nodes are kept as `None` slots, so the generic algorithms have to rely on
+/// `node_index_bound` and `contains_node` to skip them.
+pub struct AdjacencyGraph {
+    pub adjacency: Vec<Option<Vec<usize>>>,
+}
+
+impl AdjacencyGraph {
+    pub fn new(num_nodes: usize, edges: &[(usize, usize)]) -> Self {
+        let mut adjacency = vec![Some(Vec::new()); num_nodes];
+        for &(a, b) in edges {
+            adjacency[a].as_mut().unwrap().push(b);
+        }
+        Self { adjacency }
+    }
+
+    pub fn remove_node(&mut self, index: usize) {
+        self.adjacency[index] = None;
+    }
+}
+
+impl GraphView<(), ()> for AdjacencyGraph {
+    fn is_frozen(&self) -> bool {
+        false
+    }
+    fn contains_node(&self, index: usize) -> bool {
+        self.adjacency.get(index).is_some_and(|n| n.is_some())
+    }
+    fn get_node(&self, index: usize) -> Option<&()> {
+        self.contains_node(index).then_some(&())
+    }
+    fn number_nodes(&self) -> usize {
+        self.adjacency.iter().filter(|n| n.is_some()).count()
+    }
+    fn node_index_bound(&self) -> usize {
+        self.adjacency.len()
+    }
+    fn contains_edge(&self, a: usize, b: usize) -> bool {
+        self.outbound_edges(a)
+            .is_ok_and(|mut targets| targets.any(|t| t == b))
+    }
+    fn number_edges(&self) -> usize {
+        (0..self.adjacency.len())
+            .filter_map(|a| self.outbound_edges(a).ok())
+            .map(|targets| targets.count())
+            .sum()
+    }
+    fn get_edges(&self, source: usize) -> Option<Vec<(usize, &())>> {
+        let targets = self.outbound_edges(source).ok()?;
+        Some(targets.map(|t| (t, &())).collect())
+    }
+    fn contains_root_node(&self) -> bool {
+        false
+    }
+    fn get_root_node(&self) -> Option<&()> {
+        None
+    }
+    fn get_root_index(&self) -> Option<usize> {
+        None
+    }
+}
+
+impl GraphTraversal<(), ()> for AdjacencyGraph {
+    fn outbound_edges(&self, a: usize) -> Result<impl Iterator<Item = usize> + '_, GraphError> {
+        let targets = self
+            .adjacency
+            .get(a)
+            .and_then(|n| n.as_ref())
+            .ok_or(GraphError::NodeNotFound(a))?;
+        Ok(targets.iter().copied().filter(|&t| self.contains_node(t)))
+    }
+
+    fn inbound_edges(&self, a: usize) -> Result<impl Iterator<Item = usize> + '_, GraphError> {
+        if !self.contains_node(a) {
+            return Err(GraphError::NodeNotFound(a));
+        }
+        Ok((0..self.adjacency.len())
+            .filter(move |&s| self.outbound_edges(s).is_ok_and(|mut t| t.any(|t| t == a))))
+    }
+}
+
+// Implements only the required methods, so the payload-ordered sorts come from the
+// provided defaults of `GraphAlgorithms`.
+impl GraphAlgorithms<(), ()> for AdjacencyGraph {
+    fn find_cycle(&self) -> Option<Vec<usize>> {
+        algo::find_cycle(self)
+    }
+    fn has_cycle(&self) -> bool {
+        algo::has_cycle(self)
+    }
+    fn topological_sort(&self) -> Option<Vec<usize>> {
+        algo::topological_sort(self)
+    }
+    fn is_reachable(&self, start_index: usize, stop_index: usize) -> bool {
+        algo::is_reachable(self, start_index, stop_index)
+    }
+    fn shortest_path_len(&self, start_index: usize, stop_index: usize) -> Option<usize> {
+        algo::shortest_path_len(self, start_index, stop_index)
+    }
+    fn shortest_path(&self, start_index: usize, stop_index: usize) -> Option<Vec<usize>> {
+        algo::shortest_path(self, start_index, stop_index)
+    }
+}
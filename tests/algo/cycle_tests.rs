@@ -0,0 +1,66 @@
+use crate::algo::algo_test_utils::AdjacencyGraph;
+use next_graph::utils_test::gen_utils::create_csm_graph;
+use next_graph::{GraphAlgorithms, TraversalWorkspace, algo};
+
+#[test]
+fn test_find_cycle_on_custom_storage() {
+    let mut graph = AdjacencyGraph::new(4, &[(0, 1), (1, 2), (2, 3), (3, 1)]);
+    assert!(algo::has_cycle(&graph));
+    assert_eq!(algo::find_cycle(&graph), Some(vec![1, 2, 3, 1]));
+
+    // Removing a node on the cycle breaks it.
+    graph.remove_node(2);
+    assert!(!algo::has_cycle(&graph));
+    assert_eq!(algo::find_cycle(&graph), None);
+}
+
+#[test]
+fn test_find_cycle_self_loop_and_workspace() {
+    let graph = AdjacencyGraph::new(3, &[(0, 1), (1, 1), (1, 2)]);
+    let mut workspace = TraversalWorkspace::new();
+    assert_eq!(
+        algo::find_cycle_with_workspace(&graph, &mut workspace),
+        Some(vec![1, 1])
+    );
+
+    let dag = AdjacencyGraph::new(3, &[(0, 1), (1, 2)]);
+    assert_eq!(algo::find_cycle_with_workspace(&dag, &mut workspace), None);
+}
+
+#[test]
+fn test_generic_matches_csm_graph() {
+    let graph = create_csm_graph();
+    assert_eq!(algo::find_cycle(&graph), graph.find_cycle());
+    assert_eq!(algo::has_cycle(&graph), graph.has_cycle());
+}
+
+#[test]
+fn test_find_cycle_reuses_workspace_across_graphs() {
+    let cyclic = AdjacencyGraph::new(4, &[(0, 1), (1, 2), (2, 3), (3, 1)]);
+    let dag = AdjacencyGraph::new(2, &[(0, 1)]);
+    let mut workspace = TraversalWorkspace::with_capacity(4);
+
+    for _ in 0..3 {
+        assert_eq!(
+            algo::find_cycle_with_workspace(&cyclic, &mut workspace),
+            Some(vec![1, 2, 3, 1])
+        );
+        assert_eq!(algo::find_cycle_with_workspace(&dag, &mut workspace), None);
+    }
+    assert_eq!(workspace.capacity(), 4);
+}
+
+#[test]
+fn test_find_cycle_is_linear_in_out_degree() {
+    // A hub with a huge out-degree. Resuming the successor iterator of the hub by
+    // skipping already explored edges would take O(deg²) steps and hang this test.
+    let num_spokes = 200_000;
+    let edges: Vec<(usize, usize)> = (1..=num_spokes).map(|spoke| (0, spoke)).collect();
+    let mut graph = AdjacencyGraph::new(num_spokes + 1, &edges);
+    assert_eq!(algo::find_cycle(&graph), None);
+    assert!(!algo::has_cycle(&graph));
+
+    // A back edge from the last spoke is only found after all other spokes.
+    graph.adjacency[num_spokes].as_mut().unwrap().push(0);
+    assert_eq!(algo::find_cycle(&graph), Some(vec![0, num_spokes, 0]));
+}
@@ -0,0 +1,4 @@
+mod algo_test_utils;
+mod cycle_tests;
+mod path_tests;
+mod topological_sort_tests;
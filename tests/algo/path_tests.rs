@@ -0,0 +1,37 @@
+use crate::algo::algo_test_utils::AdjacencyGraph;
+use next_graph::utils_test::gen_utils::create_csm_graph;
+use next_graph::{GraphAlgorithms, TraversalWorkspace, algo};
+
+#[test]
+fn test_shortest_path_on_custom_storage() {
+    let mut graph = AdjacencyGraph::new(5, &[(0, 1), (1, 2), (2, 3), (0, 4), (4, 3)]);
+    assert_eq!(algo::shortest_path(&graph, 0, 3), Some(vec![0, 4, 3]));
+    assert_eq!(algo::shortest_path_len(&graph, 0, 3), Some(3));
+    assert_eq!(algo::shortest_path(&graph, 2, 2), Some(vec![2]));
+    assert!(!algo::is_reachable(&graph, 3, 0));
+
+    // The path is rerouted around a removed node.
+    graph.remove_node(4);
+    assert_eq!(algo::shortest_path(&graph, 0, 3), Some(vec![0, 1, 2, 3]));
+    assert_eq!(algo::shortest_path(&graph, 0, 4), None);
+    assert_eq!(algo::shortest_path(&graph, 0, 99), None);
+}
+
+#[test]
+fn test_generic_matches_csm_graph() {
+    let graph = create_csm_graph();
+    let mut workspace = TraversalWorkspace::new();
+    for a in 0..6 {
+        for b in 0..6 {
+            assert_eq!(algo::shortest_path(&graph, a, b), graph.shortest_path(a, b));
+            assert_eq!(
+                algo::shortest_path_len_with_workspace(&graph, a, b, &mut workspace),
+                graph.shortest_path_len(a, b)
+            );
+            assert_eq!(
+                algo::shortest_path_with_workspace(&graph, a, b, &mut workspace),
+                graph.shortest_path(a, b)
+            );
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use crate::algo::algo_test_utils::AdjacencyGraph;
+use next_graph::utils_test::gen_utils::create_csm_graph;
+use next_graph::{GraphAlgorithms, algo};
+
+#[test]
+fn test_topological_sort_on_custom_storage() {
+    let mut graph = AdjacencyGraph::new(4, &[(2, 0), (0, 1), (3, 1), (1, 2)]);
+    assert_eq!(algo::topological_sort(&graph), None);
+
+    // Removing a node on the cycle leaves a DAG with stable indices.
+    graph.remove_node(2);
+    assert_eq!(algo::topological_sort(&graph), Some(vec![0, 3, 1]));
+
+    let empty = AdjacencyGraph::new(0, &[]);
+    assert_eq!(algo::topological_sort(&empty), Some(vec![]));
+}
+
+#[test]
+fn test_topological_sort_by_key_on_custom_storage() {
+    // Node 3 is ready first, but equal payloads are always ordered by index.
+    let mut graph = AdjacencyGraph::new(4, &[(3, 0), (0, 1), (2, 1)]);
+    assert_eq!(
+        algo::topological_sort_by_key(&graph, |_| ()),
+        Some(vec![2, 3, 0, 1])
+    );
+
+    graph.remove_node(3);
+    assert_eq!(
+        algo::topological_sort_by(&graph, |a, b| a.cmp(b)),
+        Some(vec![0, 2, 1])
+    );
+}
+
+#[test]
+fn test_generic_matches_csm_graph() {
+    let graph = create_csm_graph();
+    assert_eq!(algo::topological_sort(&graph), graph.topological_sort());
+    assert_eq!(
+        algo::topological_sort_by_key(&graph, |_| 0),
+        graph.topological_sort()
+    );
+}
+
+#[test]
+fn test_provided_topological_sort_by_on_custom_storage() {
+    let graph = AdjacencyGraph::new(4, &[(3, 0), (0, 1), (2, 1)]);
+    assert_eq!(graph.topological_sort_by_key(|_| 0), Some(vec![2, 3, 0, 1]));
+    assert_eq!(
+        graph.topological_sort_by(|a, b| a.cmp(b)),
+        algo::topological_sort_by(&graph, |a, b| a.cmp(b))
+    );
+}
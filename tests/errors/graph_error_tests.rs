@@ -45,6 +45,37 @@ fn test_graph_contains_cycle_error() {
 }
 
 #[test]
+fn test_hyperedge_not_found_error() {
+    let error = GraphError::HyperedgeNotFound(7);
+    assert_eq!(
+        format!("{}", error),
+        "Hyperedge with index 7 not found; it may be out of bounds or have been removed."
+    );
+    assert!(error.source().is_none());
+}
+
+#[test]
+fn test_graph_contains_negative_cycle_error() {
+    let error = GraphError::GraphContainsNegativeCycle(3);
+    assert_eq!(
+        format!("{}", error),
+        "Operation failed because the graph contains a negative-weight cycle through node 3."
+    );
+    assert!(error.source().is_none());
+}
+
+#[test]
+fn test_root_not_found_error() {
+    let error = GraphError::RootNotFound;
+    assert_eq!(
+        format!("{}", error),
+        "Operation failed because the graph has no designated root node."
+    );
+    assert!(error.source().is_none());
+}
+
+#[test]
+#[allow(clippy::clone_on_copy)]
 fn test_error_traits() {
     let error1 = GraphError::NodeNotFound(5);
     let error2 = GraphError::NodeNotFound(5);
@@ -2,7 +2,10 @@
 // This entire module is only compiled and run when the "parallel" feature is enabled.
 // i.e. use cargo t --features parallel
 
-use next_graph::{DynamicGraph, Freezable, GraphMut, ParallelGraphAlgorithmsExt};
+use next_graph::{
+    DagAlgorithms, DynamicGraph, Freezable, GraphError, GraphMut, ParallelGraphAlgorithmsExt,
+    StructuralGraphAlgorithms,
+};
 
 // --- Test Helper Functions ---
 
@@ -106,6 +109,197 @@ fn test_topo_par_on_single_node_graph() {
     );
 }
 
+// --- topological_generations_par Tests ---
+
+#[test]
+fn test_topo_generations_par() {
+    let graph = create_test_dag().freeze();
+    assert_eq!(
+        graph.topological_generations_par(),
+        Ok(vec![vec![0], vec![1, 2], vec![3]])
+    );
+
+    let graph = create_disconnected_graph().freeze();
+    assert_eq!(
+        graph.topological_generations_par(),
+        Ok(vec![vec![0, 2], vec![1]])
+    );
+
+    let graph = create_cyclic_graph().freeze();
+    assert_eq!(
+        graph.topological_generations_par(),
+        Err(GraphError::GraphContainsCycle)
+    );
+}
+
+#[test]
+fn test_topo_generations_par_matches_sequential() {
+    // A layered DAG where every node links to a few nodes of later layers.
+    let mut graph = DynamicGraph::<(), ()>::new();
+    for _ in 0..200 {
+        graph.add_node(());
+    }
+    for u in 0..200 {
+        for step in [3, 17, 40] {
+            if u + step < 200 {
+                graph.add_edge(u, u + step, ()).unwrap();
+            }
+        }
+    }
+    let graph = graph.freeze();
+
+    let generations = graph.topological_generations_par().unwrap();
+    assert_eq!(Ok(generations.clone()), graph.topological_generations());
+    assert_eq!(Some(generations.concat()), graph.topological_sort_par());
+}
+
+// --- strongly_connected_components_par Tests ---
+
+#[test]
+fn test_scc_par_on_dag() {
+    let graph = create_test_dag().freeze();
+    let (components, count) = graph.strongly_connected_components_par();
+    // Every node of a DAG is its own component, labeled by its smallest member.
+    assert_eq!(count, 4);
+    assert_eq!(components, vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn test_scc_par_on_cyclic_graph() {
+    let graph = create_cyclic_graph().freeze();
+    let (components, count) = graph.strongly_connected_components_par();
+    // The back-edge 3 -> 0 puts all nodes on a cycle.
+    assert_eq!(count, 1);
+    assert_eq!(components, vec![0, 0, 0, 0]);
+}
+
+#[test]
+fn test_scc_par_on_disconnected_graph() {
+    let graph = create_disconnected_graph().freeze();
+    let (components, count) = graph.strongly_connected_components_par();
+    assert_eq!(count, 3);
+    assert_eq!(components, vec![0, 1, 2]);
+}
+
+#[test]
+fn test_scc_par_on_empty_graph() {
+    let graph = DynamicGraph::<(), ()>::new().freeze();
+    assert_eq!(graph.strongly_connected_components_par(), (vec![], 0));
+}
+
+#[test]
+fn test_scc_par_with_self_loop_and_nested_cycles() {
+    // Components: {0, 1, 2} and {3, 4, 5, 6}, where 3 also has a self-loop and
+    // the inner cycle 4 <-> 5 is nested in the outer cycle 3 -> 4 -> 5 -> 6 -> 3.
+    let mut graph = DynamicGraph::<(), ()>::new();
+    for _ in 0..7 {
+        graph.add_node(());
+    }
+    let edges = [
+        (0, 1),
+        (1, 2),
+        (2, 0),
+        (2, 3),
+        (3, 3),
+        (3, 4),
+        (4, 5),
+        (5, 4),
+        (5, 6),
+        (6, 3),
+    ];
+    for (a, b) in edges {
+        graph.add_edge(a, b, ()).unwrap();
+    }
+    let graph = graph.freeze();
+    let (components, count) = graph.strongly_connected_components_par();
+    assert_eq!(count, 2);
+    assert_eq!(components, vec![0, 0, 0, 1, 1, 1, 1]);
+}
+
+#[test]
+fn test_scc_par_matches_sequential_partition() {
+    // A larger graph: a chain of 3-cycles, with a few long back-edges and a tail.
+    let mut graph = DynamicGraph::<(), ()>::new();
+    let n = 300;
+    for _ in 0..n {
+        graph.add_node(());
+    }
+    for i in (0..n - 30).step_by(3) {
+        graph.add_edge(i, i + 1, ()).unwrap();
+        graph.add_edge(i + 1, i + 2, ()).unwrap();
+        graph.add_edge(i + 2, i, ()).unwrap();
+        graph.add_edge(i + 2, i + 3, ()).unwrap();
+    }
+    for i in n - 30..n - 1 {
+        graph.add_edge(i, i + 1, ()).unwrap();
+    }
+    graph.add_edge(90, 30, ()).unwrap();
+    graph.add_edge(200, 150, ()).unwrap();
+    let graph = graph.freeze();
+
+    let (par, par_count) = graph.strongly_connected_components_par();
+    let (seq, seq_count) = graph.strongly_connected_components();
+    assert_eq!(par_count, seq_count);
+    // Both labelings must induce the same partition of the nodes.
+    for a in 0..n {
+        for b in 0..n {
+            assert_eq!(par[a] == par[b], seq[a] == seq[b], "nodes {a} and {b}");
+        }
+    }
+}
+
+// --- weakly_connected_components_par Tests ---
+
+#[test]
+fn test_wcc_par_on_small_graphs() {
+    let graph = create_cyclic_graph().freeze();
+    assert_eq!(
+        graph.weakly_connected_components_par(),
+        (vec![0, 0, 0, 0], 1)
+    );
+
+    let graph = create_disconnected_graph().freeze();
+    assert_eq!(graph.weakly_connected_components_par(), (vec![0, 0, 1], 2));
+
+    let graph = DynamicGraph::<(), ()>::new().freeze();
+    assert_eq!(graph.weakly_connected_components_par(), (vec![], 0));
+}
+
+#[test]
+fn test_wcc_par_matches_sequential() {
+    // Many islands of different sizes, joined by edges in both directions and
+    // with high out-degrees so that the final linking phase has work to do.
+    let mut graph = DynamicGraph::<(), ()>::new();
+    let n = 5_000;
+    for _ in 0..n {
+        graph.add_node(());
+    }
+    for i in 0..n {
+        let island = i / 50;
+        if island % 7 == 3 {
+            continue; // Leave a few islands as isolated nodes.
+        }
+        for j in 1..=4 {
+            let target = island * 50 + (i * 13 + j * 7) % 50;
+            if j % 2 == 0 {
+                graph.add_edge(target, i, ()).unwrap();
+            } else {
+                graph.add_edge(i, target, ()).unwrap();
+            }
+        }
+    }
+    // One large island spanning the first half of the graph.
+    for i in (0..n / 2 - 50).step_by(50) {
+        graph.add_edge(i + 50, i, ()).unwrap();
+    }
+    let graph = graph.freeze();
+
+    assert_eq!(
+        graph.weakly_connected_components_par(),
+        graph.weakly_connected_components()
+    );
+}
+
 // --- Pathfinding and Reachability Tests ---
 
 #[test]
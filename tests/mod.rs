@@ -1,3 +1,4 @@
+mod algo;
 mod errors;
 mod extensions;
 mod types;
@@ -0,0 +1,61 @@
+use next_graph::{
+    CsmGraph, DominatorTree, DynamicGraph, Freezable, GraphError, GraphMut,
+    StructuralGraphAlgorithms,
+};
+
+/// Creates the chain 0 -> 1 -> 2 with the side branch 1 -> 3 -> 2 and the
+/// unreachable node 4 -> 2.
+fn create_graph() -> CsmGraph<(), ()> {
+    let mut g = DynamicGraph::new();
+    g.add_root_node(());
+    for _ in 1..5 {
+        g.add_node(());
+    }
+    for (a, b) in [(0, 1), (1, 2), (1, 3), (3, 2), (4, 2)] {
+        g.add_edge(a, b, ()).unwrap();
+    }
+    g.freeze()
+}
+
+fn create_tree() -> DominatorTree {
+    create_graph().dominator_tree().unwrap()
+}
+
+#[test]
+fn test_dominators_walk_up_to_root() {
+    let tree = create_tree();
+    assert_eq!(tree.number_nodes(), 5);
+    assert_eq!(tree.dominators(2).unwrap().collect::<Vec<_>>(), [2, 1, 0]);
+    assert_eq!(tree.dominators(0).unwrap().collect::<Vec<_>>(), [0]);
+    assert_eq!(tree.dominators(4).unwrap().count(), 0);
+    assert_eq!(tree.dominators(5).err(), Some(GraphError::NodeNotFound(5)));
+}
+
+#[test]
+fn test_dominates() {
+    let tree = create_tree();
+    assert!(tree.dominates(1, 2));
+    assert!(tree.dominates(0, 3));
+    assert!(tree.dominates(2, 2));
+    // Node 2 can also be reached through 1 -> 2, bypassing 3.
+    assert!(!tree.dominates(3, 2));
+    assert!(!tree.dominates(2, 1));
+    // Unreachable and out-of-bounds nodes are never dominated.
+    assert!(!tree.dominates(0, 4));
+    assert!(!tree.dominates(4, 4));
+    assert!(!tree.dominates(0, 99));
+}
+
+#[test]
+fn test_reachability_and_immediate_dominators() {
+    let tree = create_tree();
+    assert!(tree.is_reachable(0));
+    assert!(tree.is_reachable(3));
+    assert!(!tree.is_reachable(4));
+    assert!(!tree.is_reachable(99));
+
+    assert_eq!(tree.immediate_dominator(0), None);
+    assert_eq!(tree.immediate_dominator(3), Some(1));
+    assert_eq!(tree.immediate_dominator(4), None);
+    assert_eq!(tree.immediate_dominator(99), None);
+}
@@ -0,0 +1 @@
+mod dominator_tree_tests;
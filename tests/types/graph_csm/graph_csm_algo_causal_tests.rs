@@ -0,0 +1,278 @@
+use next_graph::utils_test::gen_utils::create_csm_graph;
+use next_graph::{CausalGraphAlgorithms, CsmGraph, DynamicGraph, Freezable, GraphError, GraphMut};
+
+/// Creates a causal DAG where two confounders both cause two treatments.
+///
+/// 0 -> 2, 0 -> 3, 1 -> 2, 1 -> 3, 2 -> 4, 3 -> 4, and node 5 is isolated.
+fn create_confounded_dag() -> CsmGraph<(), ()> {
+    let mut g = DynamicGraph::new();
+    for _ in 0..6 {
+        g.add_node(());
+    }
+    for (a, b) in [(0, 2), (0, 3), (1, 2), (1, 3), (2, 4), (3, 4)] {
+        g.add_edge(a, b, ()).unwrap();
+    }
+    g.freeze()
+}
+
+#[test]
+fn test_ancestors_and_descendants() {
+    let graph = create_confounded_dag();
+    assert_eq!(graph.ancestors(4), Ok(vec![0, 1, 2, 3]));
+    assert_eq!(graph.ancestors(2), Ok(vec![0, 1]));
+    assert_eq!(graph.ancestors(0), Ok(vec![]));
+    assert_eq!(graph.descendants(0), Ok(vec![2, 3, 4]));
+    assert_eq!(graph.descendants(4), Ok(vec![]));
+    assert_eq!(graph.ancestors(5), Ok(vec![]));
+    assert_eq!(graph.descendants(5), Ok(vec![]));
+
+    assert_eq!(graph.ancestors(99), Err(GraphError::NodeNotFound(99)));
+    assert_eq!(graph.descendants(99), Err(GraphError::NodeNotFound(99)));
+}
+
+#[test]
+fn test_ancestors_exclude_node_on_cycle() {
+    let mut g = DynamicGraph::new();
+    for _ in 0..3 {
+        g.add_node(());
+    }
+    g.add_edge(0, 1, ()).unwrap();
+    g.add_edge(1, 2, ()).unwrap();
+    g.add_edge(2, 1, ()).unwrap();
+    let graph = g.freeze();
+
+    assert_eq!(graph.ancestors(1), Ok(vec![0, 2]));
+    assert_eq!(graph.descendants(1), Ok(vec![2]));
+    assert_eq!(
+        graph.lowest_common_ancestors(1, 2),
+        Err(GraphError::GraphContainsCycle)
+    );
+}
+
+#[test]
+fn test_lowest_common_ancestors() {
+    let graph = create_confounded_dag();
+    // Both confounders are lowest, since neither is an ancestor of the other.
+    assert_eq!(graph.lowest_common_ancestors(2, 3), Ok(vec![0, 1]));
+    // A node is its own ancestor, so it is the LCA of itself and its descendants.
+    assert_eq!(graph.lowest_common_ancestors(2, 4), Ok(vec![2]));
+    assert_eq!(graph.lowest_common_ancestors(4, 4), Ok(vec![4]));
+    // Unrelated roots share no ancestor.
+    assert_eq!(graph.lowest_common_ancestors(0, 1), Ok(vec![]));
+    assert_eq!(graph.lowest_common_ancestors(4, 5), Ok(vec![]));
+
+    assert_eq!(
+        graph.lowest_common_ancestors(0, 99),
+        Err(GraphError::NodeNotFound(99))
+    );
+}
+
+#[test]
+fn test_lowest_common_ancestors_skips_higher_ancestors() {
+    // Edges: 0 -> 1, 0 -> 2, 1 -> 3, 2 -> 3, 3 -> 4
+    let graph = create_csm_graph();
+    assert_eq!(graph.lowest_common_ancestors(1, 2), Ok(vec![0]));
+    // Node 0 is a common ancestor of 3 and 4, but 3 lies below it.
+    assert_eq!(graph.lowest_common_ancestors(3, 4), Ok(vec![3]));
+}
+
+/// Creates a graph from an edge list, with one node per index up to `num_nodes`.
+fn create_graph(num_nodes: usize, edges: &[(usize, usize)]) -> CsmGraph<(), ()> {
+    let mut g = DynamicGraph::new();
+    for _ in 0..num_nodes {
+        g.add_node(());
+    }
+    for &(a, b) in edges {
+        g.add_edge(a, b, ()).unwrap();
+    }
+    g.freeze()
+}
+
+#[test]
+fn test_d_separation_of_basic_junctions() {
+    // Chain 0 -> 1 -> 2: observing the mediator blocks the path.
+    let chain = create_graph(3, &[(0, 1), (1, 2)]);
+    assert_eq!(chain.is_d_separated(&[0], &[2], &[]), Ok(false));
+    assert_eq!(chain.is_d_separated(&[0], &[2], &[1]), Ok(true));
+
+    // Fork 1 <- 0 -> 2: observing the common cause blocks the path.
+    let fork = create_graph(3, &[(0, 1), (0, 2)]);
+    assert_eq!(fork.is_d_separated(&[1], &[2], &[]), Ok(false));
+    assert_eq!(fork.is_d_separated(&[1], &[2], &[0]), Ok(true));
+
+    // Collider 0 -> 2 <- 1 with 2 -> 3: observing the collider or its descendant opens it.
+    let collider = create_graph(4, &[(0, 2), (1, 2), (2, 3)]);
+    assert_eq!(collider.is_d_separated(&[0], &[1], &[]), Ok(true));
+    assert_eq!(collider.is_d_separated(&[0], &[1], &[2]), Ok(false));
+    assert_eq!(collider.is_d_separated(&[0], &[1], &[3]), Ok(false));
+}
+
+#[test]
+fn test_d_separation_on_confounded_dag() {
+    let graph = create_confounded_dag();
+    assert_eq!(graph.is_d_separated(&[2], &[3], &[]), Ok(false));
+    assert_eq!(graph.is_d_separated(&[2], &[3], &[0]), Ok(false));
+    assert_eq!(graph.is_d_separated(&[2], &[3], &[0, 1]), Ok(true));
+    // Observing the common effect re-opens a path between the treatments.
+    assert_eq!(graph.is_d_separated(&[2], &[3], &[0, 1, 4]), Ok(false));
+    assert_eq!(graph.is_d_separated(&[0, 1], &[4], &[2, 3]), Ok(true));
+    assert_eq!(graph.is_d_separated(&[5], &[0, 4], &[]), Ok(true));
+
+    // Shared nodes are never separated, unless they are observed.
+    assert_eq!(graph.is_d_separated(&[5], &[5], &[]), Ok(false));
+    assert_eq!(graph.is_d_separated(&[2], &[2], &[2]), Ok(true));
+}
+
+#[test]
+fn test_d_separation_errors() {
+    let graph = create_confounded_dag();
+    assert_eq!(
+        graph.is_d_separated(&[0], &[1], &[99]),
+        Err(GraphError::NodeNotFound(99))
+    );
+
+    let cyclic = create_graph(2, &[(0, 1), (1, 0)]);
+    assert_eq!(
+        cyclic.is_d_separated(&[0], &[1], &[]),
+        Err(GraphError::GraphContainsCycle)
+    );
+}
+
+#[test]
+fn test_markov_blanket() {
+    let graph = create_confounded_dag();
+    // Parents 0 and 1, child 4, and the child's other parent 3.
+    assert_eq!(graph.markov_blanket(2), Ok(vec![0, 1, 3, 4]));
+    assert_eq!(graph.markov_blanket(0), Ok(vec![1, 2, 3]));
+    assert_eq!(graph.markov_blanket(4), Ok(vec![2, 3]));
+    assert_eq!(graph.markov_blanket(5), Ok(vec![]));
+    assert_eq!(graph.markov_blanket(99), Err(GraphError::NodeNotFound(99)));
+
+    // The blanket d-separates a node from all other nodes.
+    assert_eq!(graph.is_d_separated(&[0], &[4, 5], &[1, 2, 3]), Ok(true));
+}
+
+#[test]
+fn test_backdoor_adjustment_set() {
+    let graph = create_confounded_dag();
+    // The effect 2 -> 4 is confounded through 2 <- 0 -> 3 -> 4 and 2 <- 1 -> 3 -> 4.
+    assert_eq!(graph.backdoor_adjustment_set(2, 4), Ok(Some(vec![0, 1])));
+    // A root needs no adjustment.
+    assert_eq!(graph.backdoor_adjustment_set(0, 4), Ok(Some(vec![])));
+
+    // No set can block the direct edge from the outcome into the treatment.
+    assert_eq!(graph.backdoor_adjustment_set(2, 0), Ok(None));
+    assert_eq!(graph.backdoor_adjustment_set(2, 2), Ok(None));
+}
+
+#[test]
+fn test_backdoor_adjustment_sets_enumerates_minimal_sets() {
+    let graph = create_confounded_dag();
+    // Blocking the shared mediator 3 is enough; otherwise both confounders are needed.
+    assert_eq!(
+        graph.backdoor_adjustment_sets(2, 4, None, None),
+        Ok(vec![vec![3], vec![0, 1]])
+    );
+    assert_eq!(
+        graph.backdoor_adjustment_sets(0, 4, None, None),
+        Ok(vec![vec![]])
+    );
+    assert_eq!(graph.backdoor_adjustment_sets(2, 0, None, None), Ok(vec![]));
+
+    // The descendant 4 of treatment 2 is never part of a set.
+    assert_eq!(
+        graph.backdoor_adjustment_sets(2, 3, None, None),
+        Ok(vec![vec![0, 1]])
+    );
+}
+
+#[test]
+fn test_backdoor_adjustment_sets_with_caps() {
+    let graph = create_confounded_dag();
+    assert_eq!(
+        graph.backdoor_adjustment_sets(2, 4, Some(1), None),
+        Ok(vec![vec![3]])
+    );
+    assert_eq!(
+        graph.backdoor_adjustment_sets(2, 4, Some(0), None),
+        Ok(vec![])
+    );
+    assert_eq!(
+        graph.backdoor_adjustment_sets(2, 4, None, Some(1)),
+        Ok(vec![vec![3]])
+    );
+    assert_eq!(
+        graph.backdoor_adjustment_sets(2, 4, None, Some(0)),
+        Ok(vec![])
+    );
+    assert_eq!(
+        graph.backdoor_adjustment_sets(2, 4, Some(100), Some(100)),
+        Ok(vec![vec![3], vec![0, 1]])
+    );
+
+    // 40 confounders of 0 -> 1 that all reach the outcome through the mediator 42.
+    // Without caps, the search would walk through all 2^41 candidate subsets.
+    let mut edges = vec![(0, 1), (42, 1)];
+    for c in 2..42 {
+        edges.push((c, 0));
+        edges.push((c, 42));
+    }
+    let wide = create_graph(43, &edges);
+    assert_eq!(
+        wide.backdoor_adjustment_sets(0, 1, Some(2), None),
+        Ok(vec![vec![42]])
+    );
+    assert_eq!(
+        wide.backdoor_adjustment_sets(0, 1, None, Some(1)),
+        Ok(vec![vec![42]])
+    );
+}
+
+#[test]
+fn test_backdoor_adjustment_errors() {
+    let graph = create_confounded_dag();
+    assert_eq!(
+        graph.backdoor_adjustment_set(2, 99),
+        Err(GraphError::NodeNotFound(99))
+    );
+
+    let cyclic = create_graph(2, &[(0, 1), (1, 0)]);
+    assert_eq!(
+        cyclic.backdoor_adjustment_set(0, 1),
+        Err(GraphError::GraphContainsCycle)
+    );
+    assert_eq!(
+        cyclic.backdoor_adjustment_sets(0, 1, None, None),
+        Err(GraphError::GraphContainsCycle)
+    );
+    assert_eq!(
+        cyclic.is_frontdoor_adjustment_set(0, 1, &[]),
+        Err(GraphError::GraphContainsCycle)
+    );
+}
+
+#[test]
+fn test_frontdoor_criterion() {
+    // The classic frontdoor graph: U confounds X and Y, and X acts on Y only through M.
+    // 0 = U, 1 = X, 2 = M, 3 = Y
+    let graph = create_graph(4, &[(0, 1), (0, 3), (1, 2), (2, 3)]);
+    assert_eq!(graph.is_frontdoor_adjustment_set(1, 3, &[2]), Ok(true));
+    assert_eq!(graph.is_frontdoor_adjustment_set(1, 3, &[]), Ok(false));
+    assert_eq!(graph.is_frontdoor_adjustment_set(1, 3, &[1, 2]), Ok(false));
+
+    // A direct edge X -> Y bypasses the mediator.
+    let bypass = create_graph(4, &[(0, 1), (0, 3), (1, 2), (2, 3), (1, 3)]);
+    assert_eq!(bypass.is_frontdoor_adjustment_set(1, 3, &[2]), Ok(false));
+
+    // If U also confounds X and M, there is an open backdoor path from X to M.
+    let confounded = create_graph(4, &[(0, 1), (0, 3), (1, 2), (2, 3), (0, 2)]);
+    assert_eq!(
+        confounded.is_frontdoor_adjustment_set(1, 3, &[2]),
+        Ok(false)
+    );
+
+    assert_eq!(
+        graph.is_frontdoor_adjustment_set(1, 3, &[99]),
+        Err(GraphError::NodeNotFound(99))
+    );
+}
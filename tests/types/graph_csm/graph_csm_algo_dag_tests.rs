@@ -0,0 +1,265 @@
+use next_graph::utils_test::gen_utils::create_csm_graph;
+use next_graph::{
+    CsmGraph, DagAlgorithms, DynamicGraph, Freezable, GraphAlgorithms, GraphError, GraphMut,
+    GraphTraversal, GraphView,
+};
+
+/// Creates a DAG with redundant shortcut edges.
+///
+/// Chain 0 -> 1 -> 2 -> 3, plus the shortcuts 0 -> 2, 0 -> 3 and 1 -> 3,
+/// a duplicated edge 2 -> 3 and an unrelated edge 4 -> 3.
+fn create_shortcut_dag() -> CsmGraph<&'static str, u32> {
+    let mut g = DynamicGraph::new();
+    g.add_root_node("A");
+    g.add_node("B");
+    g.add_node("C");
+    g.add_node("D");
+    g.add_node("E");
+    g.add_edge(0, 1, 1).unwrap();
+    g.add_edge(0, 2, 2).unwrap();
+    g.add_edge(0, 3, 3).unwrap();
+    g.add_edge(1, 2, 4).unwrap();
+    g.add_edge(1, 3, 5).unwrap();
+    g.add_edge(2, 3, 6).unwrap();
+    g.add_edge(2, 3, 7).unwrap();
+    g.add_edge(4, 3, 8).unwrap();
+    g.freeze()
+}
+
+fn create_cyclic_graph() -> CsmGraph<(), ()> {
+    let mut g = DynamicGraph::new();
+    g.add_node(());
+    g.add_node(());
+    g.add_edge(0, 1, ()).unwrap();
+    g.add_edge(1, 0, ()).unwrap();
+    g.freeze()
+}
+
+#[test]
+fn test_topological_generations() {
+    // The duplicated edge 2 -> 3 must only delay node 3 once.
+    let graph = create_shortcut_dag();
+    assert_eq!(
+        graph.topological_generations(),
+        Ok(vec![vec![0, 4], vec![1], vec![2], vec![3]])
+    );
+
+    let empty = DynamicGraph::<(), ()>::new().freeze();
+    assert_eq!(empty.topological_generations(), Ok(vec![]));
+
+    assert_eq!(
+        create_cyclic_graph().topological_generations(),
+        Err(GraphError::GraphContainsCycle)
+    );
+}
+
+#[test]
+fn test_topological_generations_flatten_to_valid_order() {
+    let graph = create_csm_graph();
+    let generations = graph.topological_generations().unwrap();
+    assert_eq!(generations, vec![vec![0], vec![1, 2], vec![3], vec![4]]);
+
+    let order = generations.concat();
+    let mut position = vec![0; order.len()];
+    for (i, &node) in order.iter().enumerate() {
+        position[node] = i;
+    }
+    for u in 0..graph.number_nodes() {
+        for v in graph.outbound_edges(u).unwrap() {
+            assert!(position[u] < position[v]);
+        }
+    }
+}
+
+#[test]
+fn test_transitive_closure_matches_is_reachable() {
+    let graph = create_shortcut_dag();
+    let closure = graph.transitive_closure().unwrap();
+    assert_eq!(closure.number_nodes(), 5);
+
+    for a in 0..5 {
+        for b in 0..5 {
+            assert_eq!(
+                closure.is_reachable(a, b),
+                graph.is_reachable(a, b),
+                "{a} -> {b}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_transitive_closure_on_large_dag() {
+    // More than 64 nodes, so rows span several words.
+    let mut g = DynamicGraph::new();
+    for _ in 0..200 {
+        g.add_node(());
+    }
+    for i in 0..199 {
+        g.add_edge(i, i + 1, ()).unwrap();
+        if i % 3 == 0 && i + 70 < 200 {
+            g.add_edge(i, i + 70, ()).unwrap();
+        }
+    }
+    let graph = g.freeze();
+    let closure = graph.transitive_closure().unwrap();
+
+    assert!(closure.is_reachable(0, 199));
+    assert!(closure.is_reachable(63, 64));
+    assert!(!closure.is_reachable(130, 129));
+    assert_eq!(closure.reachable_from(100).unwrap().count(), 99);
+}
+
+#[test]
+fn test_transitive_reduction_removes_shortcuts() {
+    let graph = create_shortcut_dag();
+    let reduced = graph.transitive_reduction().unwrap();
+
+    // Nodes, payloads and the root are preserved.
+    assert_eq!(reduced.number_nodes(), 5);
+    assert_eq!(reduced.get_node(2), Some(&"C"));
+    assert_eq!(reduced.get_root_index(), Some(0));
+
+    // Only the chain and the unrelated edge remain, with their original weights.
+    assert_eq!(reduced.number_edges(), 4);
+    assert_eq!(reduced.get_edges(0), Some(vec![(1, &1)]));
+    assert_eq!(reduced.get_edges(1), Some(vec![(2, &4)]));
+    assert_eq!(reduced.get_edges(2), Some(vec![(3, &6)]));
+    assert_eq!(reduced.get_edges(4), Some(vec![(3, &8)]));
+
+    // Reachability is unchanged.
+    for a in 0..5 {
+        for b in 0..5 {
+            assert_eq!(reduced.is_reachable(a, b), graph.is_reachable(a, b));
+        }
+    }
+}
+
+#[test]
+fn test_transitive_reduction_keeps_minimal_dag() {
+    // The diamond 0 -> {1, 2} -> 3 has no redundant edges.
+    let graph = create_csm_graph();
+    let reduced = graph.transitive_reduction().unwrap();
+    assert_eq!(reduced.number_edges(), graph.number_edges());
+
+    let empty = DynamicGraph::<(), ()>::new().freeze();
+    assert_eq!(empty.transitive_reduction().unwrap().number_nodes(), 0);
+}
+
+#[test]
+fn test_transitive_algorithms_reject_cycles() {
+    let graph = create_cyclic_graph();
+    assert_eq!(
+        graph.transitive_closure().err(),
+        Some(GraphError::GraphContainsCycle)
+    );
+    assert_eq!(
+        graph.transitive_reduction().err(),
+        Some(GraphError::GraphContainsCycle)
+    );
+}
+
+#[test]
+fn test_longest_path() {
+    // Edges: 0 -> 1 (10), 0 -> 2 (20), 1 -> 3 (30), 2 -> 3 (40), 3 -> 4 (50)
+    let graph = create_csm_graph();
+    assert_eq!(graph.longest_path(), Ok((vec![0, 2, 3, 4], 110)));
+}
+
+#[test]
+fn test_longest_path_skips_negative_prefix() {
+    let mut g = DynamicGraph::new();
+    for _ in 0..4 {
+        g.add_node(());
+    }
+    g.add_edge(0, 1, -5.0).unwrap();
+    g.add_edge(1, 2, 10.0).unwrap();
+    g.add_edge(2, 3, -1.0).unwrap();
+    let graph = g.freeze();
+
+    // The best path starts after the negative edge and ends before the next one.
+    assert_eq!(graph.longest_path(), Ok((vec![1, 2], 10.0)));
+}
+
+#[test]
+fn test_longest_path_trivial_graphs() {
+    let mut g = DynamicGraph::<(), u32>::new();
+    g.add_node(());
+    g.add_node(());
+    let graph = g.freeze();
+    assert_eq!(graph.longest_path(), Ok((vec![0], 0)));
+
+    let empty = DynamicGraph::<(), u32>::new().freeze();
+    assert_eq!(empty.longest_path(), Ok((vec![], 0)));
+}
+
+#[test]
+fn test_critical_path_over_edge_weights() {
+    // Edges: 0 -> 1 (10), 0 -> 2 (20), 1 -> 3 (30), 2 -> 3 (40), 3 -> 4 (50)
+    let graph = create_csm_graph();
+    assert_eq!(
+        graph.critical_path(|&w| w as f64 / 10.0),
+        Ok((vec![0, 2, 3, 4], 11.0))
+    );
+
+    // Counting stages instead of weights ties both branches; the first one wins.
+    assert_eq!(graph.critical_path(|_| 1u8), Ok((vec![0, 1, 3, 4], 3)));
+
+    // The weights do not have to be numbers themselves.
+    let mut g = DynamicGraph::new();
+    for _ in 0..3 {
+        g.add_node(());
+    }
+    g.add_edge(0, 1, ("build", 4)).unwrap();
+    g.add_edge(1, 2, ("test", 2)).unwrap();
+    g.add_edge(0, 2, ("lint", 1)).unwrap();
+    let graph = g.freeze();
+    assert_eq!(
+        graph.critical_path(|&(_, minutes)| minutes),
+        Ok((vec![0, 1, 2], 6))
+    );
+
+    let empty = DynamicGraph::<(), ()>::new().freeze();
+    assert_eq!(empty.critical_path(|_| 1u64), Ok((vec![], 0)));
+}
+
+#[test]
+fn test_critical_path_by_durations() {
+    // Edges: 0 -> 1, 0 -> 2, 1 -> 3, 2 -> 3, 3 -> 4
+    let graph = create_csm_graph();
+    let durations = [1, 5, 2, 1, 1];
+    assert_eq!(
+        graph.critical_path_by_durations(|n| durations[n]),
+        Ok((vec![0, 1, 3, 4], 8))
+    );
+
+    // With a slow task on the other branch, the critical path moves.
+    let durations = [1.0, 5.0, 7.5, 1.0, 1.0];
+    assert_eq!(
+        graph.critical_path_by_durations(|n| durations[n]),
+        Ok((vec![0, 2, 3, 4], 10.5))
+    );
+
+    let empty = DynamicGraph::<(), ()>::new().freeze();
+    assert_eq!(empty.critical_path_by_durations(|_| 1u64), Ok((vec![], 0)));
+}
+
+#[test]
+fn test_longest_paths_reject_cycles() {
+    let mut g = DynamicGraph::new();
+    g.add_node(());
+    g.add_node(());
+    g.add_edge(0, 1, 1).unwrap();
+    g.add_edge(1, 0, 1).unwrap();
+    let graph = g.freeze();
+
+    assert_eq!(graph.longest_path(), Err(GraphError::GraphContainsCycle));
+    assert_eq!(
+        graph.critical_path(|&w| w),
+        Err(GraphError::GraphContainsCycle)
+    );
+    assert_eq!(
+        graph.critical_path_by_durations(|_| 1),
+        Err(GraphError::GraphContainsCycle)
+    );
+}
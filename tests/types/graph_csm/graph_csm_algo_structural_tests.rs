@@ -0,0 +1,416 @@
+use next_graph::utils_test::gen_utils::create_csm_graph;
+use next_graph::{
+    CsmGraph, DynamicGraph, Freezable, GraphAlgorithms, GraphError, GraphMut, GraphView,
+    StructuralGraphAlgorithms,
+};
+
+/// Creates a graph with two feedback loops joined by a bridge edge.
+///
+/// 0 <-> 1 -> 2 -> 3 -> 4 -> 2, plus 5 isolated and 4 -> 6.
+/// SCCs: {0, 1}, {2, 3, 4}, {5}, {6}.
+fn create_looped_graph() -> CsmGraph<(), u32> {
+    let mut g = DynamicGraph::new();
+    for _ in 0..7 {
+        g.add_node(());
+    }
+    g.add_edge(0, 1, 1).unwrap();
+    g.add_edge(1, 0, 1).unwrap();
+    g.add_edge(1, 2, 1).unwrap();
+    g.add_edge(2, 3, 1).unwrap();
+    g.add_edge(3, 4, 1).unwrap();
+    g.add_edge(4, 2, 1).unwrap();
+    g.add_edge(4, 6, 1).unwrap();
+    g.freeze()
+}
+
+#[test]
+fn test_scc_on_dag() {
+    let graph = create_csm_graph();
+    let (components, count) = graph.strongly_connected_components();
+    assert_eq!(count, 5);
+
+    // Every node is its own component and ids follow the topological order.
+    for n in 0..5 {
+        for (target, _) in graph.get_edges(n).unwrap() {
+            assert!(components[n] < components[target]);
+        }
+    }
+}
+
+#[test]
+fn test_scc_with_loops() {
+    let graph = create_looped_graph();
+    let (components, count) = graph.strongly_connected_components();
+    assert_eq!(count, 4);
+
+    assert_eq!(components[0], components[1]);
+    assert_eq!(components[2], components[3]);
+    assert_eq!(components[3], components[4]);
+    assert_ne!(components[0], components[2]);
+    assert_ne!(components[5], components[6]);
+
+    // Edges between components point from lower to higher ids.
+    assert!(components[1] < components[2]);
+    assert!(components[4] < components[6]);
+}
+
+#[test]
+fn test_scc_self_loop_and_empty() {
+    let mut g = DynamicGraph::new();
+    let n0 = g.add_node(());
+    g.add_edge(n0, n0, ()).unwrap();
+    let graph = g.freeze();
+    assert_eq!(graph.strongly_connected_components(), (vec![0], 1));
+
+    let graph = CsmGraph::<(), ()>::new();
+    assert_eq!(graph.strongly_connected_components(), (vec![], 0));
+}
+
+#[test]
+fn test_scc_deep_chain_does_not_overflow() {
+    let mut g = DynamicGraph::new();
+    let num_nodes = 200_000;
+    for _ in 0..num_nodes {
+        g.add_node(());
+    }
+    for i in 0..num_nodes - 1 {
+        g.add_edge(i, i + 1, ()).unwrap();
+    }
+    g.add_edge(num_nodes - 1, 0, ()).unwrap();
+    let graph = g.freeze();
+
+    let (_, count) = graph.strongly_connected_components();
+    assert_eq!(count, 1);
+}
+
+#[test]
+fn test_condensation() {
+    let graph = create_looped_graph();
+    assert!(graph.has_cycle());
+
+    let (components, count) = graph.strongly_connected_components();
+    let condensed = graph.condensation();
+
+    assert_eq!(condensed.number_nodes(), count);
+    assert!(!condensed.has_cycle());
+    assert_eq!(condensed.get_node(components[0]), Some(&vec![0, 1]));
+    assert_eq!(condensed.get_node(components[3]), Some(&vec![2, 3, 4]));
+
+    // Internal edges are dropped: only 1 -> 2 and 4 -> 6 remain.
+    assert_eq!(condensed.number_edges(), 2);
+    assert!(condensed.contains_edge(components[1], components[2]));
+    assert!(condensed.contains_edge(components[4], components[6]));
+
+    // The condensation of a cyclic graph can be sorted topologically.
+    assert_eq!(condensed.topological_sort(), Some(vec![0, 1, 2, 3]));
+}
+
+#[test]
+fn test_condensation_merges_parallel_edges() {
+    let mut g = DynamicGraph::new();
+    for _ in 0..4 {
+        g.add_node(());
+    }
+    g.add_edge(0, 1, ()).unwrap();
+    g.add_edge(1, 0, ()).unwrap();
+    g.add_edge(0, 2, ()).unwrap();
+    g.add_edge(1, 2, ()).unwrap();
+    g.add_edge(2, 3, ()).unwrap();
+    g.add_edge(3, 2, ()).unwrap();
+    let graph = g.freeze();
+
+    let condensed = graph.condensation();
+    assert_eq!(condensed.number_nodes(), 2);
+    assert_eq!(condensed.get_edges(0), Some(vec![(1, &2)]));
+}
+
+#[test]
+fn test_wcc_ignores_edge_direction() {
+    let graph = create_looped_graph();
+    let (components, count) = graph.weakly_connected_components();
+    // {0, 1, 2, 3, 4, 6} are joined by edges in either direction, 5 is isolated.
+    assert_eq!(count, 2);
+    assert_eq!(components, vec![0, 0, 0, 0, 0, 1, 0]);
+}
+
+#[test]
+fn test_wcc_labels_by_smallest_node() {
+    // Two islands that are only joined against the edge direction: 3 -> 0 and 4 -> 1 -> 2.
+    let mut g = DynamicGraph::new();
+    for _ in 0..5 {
+        g.add_node(());
+    }
+    g.add_edge(3, 0, ()).unwrap();
+    g.add_edge(4, 1, ()).unwrap();
+    g.add_edge(1, 2, ()).unwrap();
+    let graph = g.freeze();
+
+    let (components, count) = graph.weakly_connected_components();
+    assert_eq!(count, 2);
+    assert_eq!(components, vec![0, 1, 1, 0, 1]);
+
+    let empty = DynamicGraph::<(), ()>::new().freeze();
+    assert_eq!(empty.weakly_connected_components(), (vec![], 0));
+}
+
+/// Creates the complete directed graph on `n` nodes (every ordered pair is an edge).
+fn create_complete_graph(n: usize) -> CsmGraph<(), ()> {
+    let mut g = DynamicGraph::new();
+    for _ in 0..n {
+        g.add_node(());
+    }
+    for a in 0..n {
+        for b in 0..n {
+            if a != b {
+                g.add_edge(a, b, ()).unwrap();
+            }
+        }
+    }
+    g.freeze()
+}
+
+#[test]
+fn test_cycles_on_dag_and_looped_graph() {
+    let dag = create_csm_graph();
+    assert_eq!(dag.cycles(None, None).count(), 0);
+
+    let graph = create_looped_graph();
+    let cycles: Vec<Vec<usize>> = graph.cycles(None, None).collect();
+    assert_eq!(cycles, vec![vec![0, 1, 0], vec![2, 3, 4, 2]]);
+}
+
+#[test]
+fn test_cycles_complete_graph() {
+    // K4 has 6 two-cycles, 8 three-cycles and 6 four-cycles.
+    let graph = create_complete_graph(4);
+    let cycles: Vec<Vec<usize>> = graph.cycles(None, None).collect();
+    assert_eq!(cycles.len(), 20);
+
+    // Every cycle is closed, elementary, starts at its smallest node and is unique.
+    for cycle in &cycles {
+        assert_eq!(cycle.first(), cycle.last());
+        let body = &cycle[..cycle.len() - 1];
+        assert_eq!(body.iter().min(), Some(&cycle[0]));
+        let mut sorted = body.to_vec();
+        sorted.sort_unstable();
+        sorted.dedup();
+        assert_eq!(sorted.len(), body.len());
+    }
+    let mut unique = cycles.clone();
+    unique.sort();
+    unique.dedup();
+    assert_eq!(unique.len(), cycles.len());
+
+    // K5: 10 + 2 * 10 + 6 * 5 + 24 = 84 cycles.
+    assert_eq!(create_complete_graph(5).cycles(None, None).count(), 84);
+}
+
+#[test]
+fn test_cycles_with_caps() {
+    let graph = create_complete_graph(4);
+    assert_eq!(graph.cycles(Some(2), None).count(), 6);
+    assert_eq!(graph.cycles(Some(3), None).count(), 14);
+    assert!(graph.cycles(Some(3), None).all(|c| c.len() <= 4));
+    assert_eq!(graph.cycles(None, Some(5)).count(), 5);
+    assert_eq!(graph.cycles(Some(2), Some(100)).count(), 6);
+    assert_eq!(graph.cycles(None, Some(0)).count(), 0);
+    assert_eq!(graph.cycles(Some(1), None).count(), 0);
+    assert_eq!(graph.cycles(Some(0), None).count(), 0);
+
+    // The iterator is lazy: the first cycles of a dense graph are available immediately.
+    let dense = create_complete_graph(12);
+    let first: Vec<Vec<usize>> = dense.cycles(None, None).take(3).collect();
+    assert_eq!(first.len(), 3);
+    assert_eq!(first[0], vec![0, 1, 0]);
+}
+
+#[test]
+fn test_cycles_self_loop_and_parallel_edges() {
+    let mut g = DynamicGraph::new();
+    for _ in 0..3 {
+        g.add_node(());
+    }
+    g.add_edge(0, 0, ()).unwrap();
+    g.add_edge(1, 2, ()).unwrap();
+    g.add_edge(1, 2, ()).unwrap();
+    g.add_edge(2, 1, ()).unwrap();
+    let graph = g.freeze();
+
+    let cycles: Vec<Vec<usize>> = graph.cycles(None, None).collect();
+    assert_eq!(cycles, vec![vec![0, 0], vec![1, 2, 1]]);
+    assert_eq!(
+        graph.cycles(Some(1), None).collect::<Vec<_>>(),
+        vec![vec![0, 0]]
+    );
+    assert_eq!(graph.cycles(Some(0), None).count(), 0);
+}
+
+/// Creates a rooted control-flow graph with a loop and an unreachable node.
+///
+/// 0 -> 1, 1 -> 2, 1 -> 3, 1 -> 5, 2 -> 4, 3 -> 4, 4 -> 1, and 6 -> 4.
+fn create_control_flow_graph() -> CsmGraph<(), ()> {
+    let mut g = DynamicGraph::new();
+    g.add_root_node(());
+    for _ in 1..7 {
+        g.add_node(());
+    }
+    for (a, b) in [
+        (0, 1),
+        (1, 2),
+        (1, 3),
+        (1, 5),
+        (2, 4),
+        (3, 4),
+        (4, 1),
+        (6, 4),
+    ] {
+        g.add_edge(a, b, ()).unwrap();
+    }
+    g.freeze()
+}
+
+#[test]
+fn test_dominator_tree() {
+    let graph = create_control_flow_graph();
+    let tree = graph.dominator_tree().unwrap();
+    assert_eq!(tree.root(), 0);
+
+    let idoms: Vec<_> = (0..7).map(|n| tree.immediate_dominator(n)).collect();
+    assert_eq!(
+        idoms,
+        vec![None, Some(0), Some(1), Some(1), Some(1), Some(1), None]
+    );
+    assert!(!tree.is_reachable(6));
+}
+
+#[test]
+fn test_dominator_tree_with_irreducible_loop() {
+    // The loop 1 <-> 2 has two entries, so neither node dominates the other.
+    let mut g = DynamicGraph::new();
+    for _ in 0..4 {
+        g.add_node(());
+    }
+    for (a, b) in [(0, 1), (0, 2), (1, 2), (2, 1), (1, 3), (2, 3)] {
+        g.add_edge(a, b, ()).unwrap();
+    }
+    let graph = g.freeze();
+
+    let tree = graph.dominator_tree_from(0).unwrap();
+    for node in 1..4 {
+        assert_eq!(tree.immediate_dominator(node), Some(0));
+    }
+}
+
+#[test]
+fn test_post_dominator_tree() {
+    // Edges: 0 -> 1, 0 -> 2, 1 -> 3, 2 -> 3, 3 -> 4
+    let graph = create_csm_graph();
+    let tree = graph.dominator_tree_from(0).unwrap();
+    let idoms: Vec<_> = (0..5).map(|n| tree.immediate_dominator(n)).collect();
+    assert_eq!(idoms, vec![None, Some(0), Some(0), Some(0), Some(3)]);
+
+    let post_tree = graph.post_dominator_tree(4).unwrap();
+    assert_eq!(post_tree.root(), 4);
+    let ipdoms: Vec<_> = (0..5).map(|n| post_tree.immediate_dominator(n)).collect();
+    assert_eq!(ipdoms, vec![Some(3), Some(3), Some(3), Some(4), None]);
+
+    // In the control-flow graph, every node that reaches the exit 5 must pass node 1.
+    let graph = create_control_flow_graph();
+    let post_tree = graph.post_dominator_tree(5).unwrap();
+    let ipdoms: Vec<_> = (0..7).map(|n| post_tree.immediate_dominator(n)).collect();
+    assert_eq!(
+        ipdoms,
+        vec![Some(1), Some(5), Some(4), Some(4), Some(1), None, Some(4)]
+    );
+}
+
+#[test]
+fn test_dominator_tree_errors() {
+    let graph = create_csm_graph();
+    assert_eq!(graph.dominator_tree().err(), Some(GraphError::RootNotFound));
+    assert_eq!(
+        graph.dominator_tree_from(99).err(),
+        Some(GraphError::NodeNotFound(99))
+    );
+    assert_eq!(
+        graph.post_dominator_tree(99).err(),
+        Some(GraphError::NodeNotFound(99))
+    );
+}
+
+/// Creates a dependency graph with two triangles joined by the bridge 2 -> 3.
+///
+/// Triangles 0 -> 1 -> 2 -> 0 and 3 -> 4 -> 5 -> 3, node 6 isolated, a self-loop
+/// on 7, the mutual edges 8 <-> 9 and the pendant edge 8 -> 10.
+fn create_dependency_graph() -> CsmGraph<(), ()> {
+    let mut g = DynamicGraph::new();
+    for _ in 0..11 {
+        g.add_node(());
+    }
+    let edges = [
+        (0, 1),
+        (1, 2),
+        (2, 0),
+        (2, 3),
+        (3, 4),
+        (4, 5),
+        (5, 3),
+        (7, 7),
+        (8, 9),
+        (9, 8),
+        (8, 10),
+    ];
+    for (a, b) in edges {
+        g.add_edge(a, b, ()).unwrap();
+    }
+    g.freeze()
+}
+
+#[test]
+fn test_articulation_points_and_bridges() {
+    let graph = create_dependency_graph();
+    assert_eq!(graph.articulation_points(), vec![2, 3, 8]);
+    // The mutual edges 8 <-> 9 back each other up.
+    assert_eq!(graph.bridges(), vec![(2, 3), (8, 10)]);
+
+    // Edges: 0 -> 1, 0 -> 2, 1 -> 3, 2 -> 3, 3 -> 4
+    let diamond = create_csm_graph();
+    assert_eq!(diamond.articulation_points(), vec![3]);
+    assert_eq!(diamond.bridges(), vec![(3, 4)]);
+}
+
+#[test]
+fn test_bridges_keep_edge_direction() {
+    // 0 -> 1 <- 2: the middle node is reached through a backward edge.
+    let mut g = DynamicGraph::new();
+    for _ in 0..3 {
+        g.add_node(());
+    }
+    g.add_edge(0, 1, ()).unwrap();
+    g.add_edge(2, 1, ()).unwrap();
+    let graph = g.freeze();
+
+    assert_eq!(graph.articulation_points(), vec![1]);
+    assert_eq!(graph.bridges(), vec![(0, 1), (2, 1)]);
+
+    let empty = DynamicGraph::<(), ()>::new().freeze();
+    assert!(empty.articulation_points().is_empty());
+    assert!(empty.bridges().is_empty());
+}
+
+#[test]
+fn test_cut_structure_on_deep_path() {
+    // A long path would overflow the call stack with a recursive DFS.
+    let num_nodes = 100_000;
+    let mut g = DynamicGraph::with_capacity(num_nodes, None);
+    for _ in 0..num_nodes {
+        g.add_node(());
+    }
+    for i in 0..num_nodes - 1 {
+        g.add_edge(i, i + 1, ()).unwrap();
+    }
+    let graph = g.freeze();
+
+    assert_eq!(graph.articulation_points().len(), num_nodes - 2);
+    assert_eq!(graph.bridges().len(), num_nodes - 1);
+}
@@ -200,6 +200,58 @@ fn test_topological_sort_single_node_no_edges() {
     assert_eq!(csm_graph.topological_sort(), Some(vec![0]));
 }
 
+#[test]
+fn test_topological_sort_by_payload() {
+    let mut dynamic_graph = DynamicGraph::new();
+    let build = dynamic_graph.add_node("build");
+    let test = dynamic_graph.add_node("test");
+    let lint = dynamic_graph.add_node("lint");
+    let deploy = dynamic_graph.add_node("deploy");
+    let docs = dynamic_graph.add_node("docs");
+    dynamic_graph.add_edge(build, test, ()).unwrap();
+    dynamic_graph.add_edge(build, lint, ()).unwrap();
+    dynamic_graph.add_edge(test, deploy, ()).unwrap();
+    dynamic_graph.add_edge(lint, deploy, ()).unwrap();
+    let graph = dynamic_graph.freeze();
+
+    // The FIFO queue follows the node indices.
+    assert_eq!(
+        graph.topological_sort(),
+        Some(vec![build, docs, test, lint, deploy])
+    );
+
+    // Ready nodes are picked alphabetically: build, docs, lint, test, deploy.
+    assert_eq!(
+        graph.topological_sort_by_key(|name| *name),
+        Some(vec![build, docs, lint, test, deploy])
+    );
+
+    // Reversing the comparator prefers docs, but still keeps every dependency.
+    assert_eq!(
+        graph.topological_sort_by(|a, b| b.cmp(a)),
+        Some(vec![docs, build, test, lint, deploy])
+    );
+}
+
+#[test]
+fn test_topological_sort_by_ties_and_cycles() {
+    // All payloads are equal, so the order falls back to the node indices.
+    let graph = create_csm_graph();
+    assert_eq!(
+        graph.topological_sort_by_key(|_| 0),
+        Some(vec![0, 1, 2, 3, 4])
+    );
+
+    let mut dynamic_graph = DynamicGraph::new();
+    let n0 = dynamic_graph.add_node(1);
+    let n1 = dynamic_graph.add_node(2);
+    dynamic_graph.add_edge(n0, n1, ()).unwrap();
+    dynamic_graph.add_edge(n1, n0, ()).unwrap();
+    let graph_with_cycle = dynamic_graph.freeze();
+    assert_eq!(graph_with_cycle.topological_sort_by_key(|&n| n), None);
+    assert_eq!(graph_with_cycle.topological_sort_by(|a, b| a.cmp(b)), None);
+}
+
 #[test]
 fn test_find_cycle_self_loop() {
     let mut dynamic_graph = DynamicGraph::new();
@@ -211,6 +263,21 @@ fn test_find_cycle_self_loop() {
     assert_eq!(cycle.unwrap(), vec![0, 0]);
 }
 
+#[test]
+fn test_find_cycle_self_loop_on_non_root_node() {
+    // The self-loop is found while the DFS started at node 0 is still on the stack,
+    // so the cycle must not include the path from the DFS root.
+    let mut dynamic_graph = DynamicGraph::new();
+    let n0 = dynamic_graph.add_node("A".to_string());
+    let n1 = dynamic_graph.add_node("B".to_string());
+    let n2 = dynamic_graph.add_node("C".to_string());
+    dynamic_graph.add_edge(n0, n1, 1).unwrap();
+    dynamic_graph.add_edge(n1, n2, 1).unwrap();
+    dynamic_graph.add_edge(n2, n2, 1).unwrap(); // Self-loop
+    let graph = dynamic_graph.freeze();
+    assert_eq!(graph.find_cycle(), Some(vec![2, 2]));
+}
+
 #[test]
 fn test_find_cycle_multiple_cycles() {
     let mut dynamic_graph = DynamicGraph::new();
@@ -305,6 +372,7 @@ fn test_shortest_path_len_complex() {
 }
 
 #[test]
+#[allow(clippy::nonminimal_bool)]
 fn test_shortest_path_complex() {
     let mut dynamic_graph = DynamicGraph::new();
     let n0 = dynamic_graph.add_node("A".to_string());
@@ -0,0 +1,300 @@
+use next_graph::utils_test::gen_utils::create_csm_graph;
+use next_graph::{
+    CsmGraph, DynamicGraph, Freezable, GraphAlgorithms, GraphError, GraphMut, GraphView,
+    WeightedGraphAlgorithms,
+};
+
+/// Creates a graph where the fewest hops and the cheapest path differ.
+///
+/// 0 --1--> 1 --1--> 2
+/// |                 ^
+/// +--------5--------+
+fn create_detour_graph() -> CsmGraph<(), u64> {
+    let mut g = DynamicGraph::new();
+    let n0 = g.add_node(());
+    let n1 = g.add_node(());
+    let n2 = g.add_node(());
+    g.add_node(()); // Node 3, unreachable
+    g.add_edge(n0, n1, 1).unwrap();
+    g.add_edge(n1, n2, 1).unwrap();
+    g.add_edge(n0, n2, 5).unwrap();
+    g.freeze()
+}
+
+#[test]
+fn test_shortest_path_weighted() {
+    let graph = create_csm_graph();
+
+    assert_eq!(graph.shortest_path_weighted(0, 0), Some((vec![0], 0)));
+    assert_eq!(graph.shortest_path_weighted(0, 1), Some((vec![0, 1], 10)));
+    assert_eq!(
+        graph.shortest_path_weighted(0, 4),
+        Some((vec![0, 1, 3, 4], 90))
+    );
+    assert_eq!(graph.shortest_path_weighted(4, 0), None); // No path
+    assert_eq!(graph.shortest_path_weighted(0, 99), None); // Non-existent target
+    assert_eq!(graph.shortest_path_weighted(99, 0), None); // Non-existent source
+}
+
+#[test]
+fn test_shortest_path_weighted_prefers_cheaper_detour() {
+    let graph = create_detour_graph();
+
+    // BFS takes the direct edge, Dijkstra takes the cheaper detour.
+    assert_eq!(graph.shortest_path(0, 2), Some(vec![0, 2]));
+    assert_eq!(graph.shortest_path_weighted(0, 2), Some((vec![0, 1, 2], 2)));
+    assert_eq!(graph.shortest_path_weighted(0, 3), None);
+}
+
+#[test]
+fn test_shortest_path_weighted_f64() {
+    let mut g = DynamicGraph::new();
+    let n0 = g.add_node("A");
+    let n1 = g.add_node("B");
+    let n2 = g.add_node("C");
+    g.add_edge(n0, n1, 0.25).unwrap();
+    g.add_edge(n1, n2, 0.5).unwrap();
+    g.add_edge(n0, n2, 1.0).unwrap();
+    let graph = g.freeze();
+
+    let (path, cost) = graph.shortest_path_weighted(n0, n2).unwrap();
+    assert_eq!(path, vec![n0, n1, n2]);
+    assert!((cost - 0.75_f64).abs() < f64::EPSILON);
+}
+
+#[test]
+fn test_dijkstra_all() {
+    let graph = create_detour_graph();
+    assert_eq!(
+        graph.dijkstra_all(0),
+        Ok(vec![Some(0), Some(1), Some(2), None])
+    );
+    assert_eq!(graph.dijkstra_all(2), Ok(vec![None, None, Some(0), None]));
+    assert_eq!(graph.dijkstra_all(99), Err(GraphError::NodeNotFound(99)));
+
+    let graph = create_csm_graph();
+    assert_eq!(
+        graph.dijkstra_all(0),
+        Ok(vec![Some(0), Some(10), Some(20), Some(40), Some(90)])
+    );
+}
+
+/// Creates a graph with signed weights and no negative cycle.
+///
+/// 0 --4--> 1 --(-3)--> 2 --2--> 3
+/// |                    ^
+/// +---------2----------+
+fn create_signed_graph() -> DynamicGraph<(), i64> {
+    let mut g = DynamicGraph::new();
+    for _ in 0..5 {
+        g.add_node(());
+    }
+    g.add_edge(0, 1, 4).unwrap();
+    g.add_edge(1, 2, -3).unwrap();
+    g.add_edge(0, 2, 2).unwrap();
+    g.add_edge(2, 3, 2).unwrap();
+    // Node 4 is unreachable.
+    g
+}
+
+#[test]
+fn test_bellman_ford_negative_weights() {
+    let graph = create_signed_graph().freeze();
+    let (distances, predecessors) = graph.bellman_ford(0).unwrap();
+
+    assert_eq!(distances, vec![Some(0), Some(4), Some(1), Some(3), None]);
+    assert_eq!(predecessors, vec![None, Some(0), Some(1), Some(2), None]);
+    assert_eq!(graph.find_negative_cycle(), None);
+}
+
+#[test]
+fn test_bellman_ford_matches_dijkstra() {
+    let graph = create_csm_graph();
+    let (distances, _) = graph.bellman_ford(0).unwrap();
+    assert_eq!(Ok(distances), graph.dijkstra_all(0));
+}
+
+#[test]
+fn test_bellman_ford_invalid_start() {
+    let graph = create_signed_graph().freeze();
+    assert_eq!(graph.bellman_ford(99), Err(GraphError::NodeNotFound(99)));
+}
+
+#[test]
+fn test_bellman_ford_negative_cycle() {
+    let mut g = create_signed_graph();
+    g.add_edge(3, 1, -1).unwrap(); // Cycle 1 -> 2 -> 3 -> 1 with weight -2
+    let graph = g.freeze();
+
+    let cycle_from_start = graph.negative_cycle_from(0).unwrap().unwrap();
+    match graph.bellman_ford(0) {
+        Err(GraphError::GraphContainsNegativeCycle(node)) => {
+            assert!([1, 2, 3].contains(&node));
+            assert!(cycle_from_start.contains(&node));
+        }
+        other => panic!("Expected a negative cycle error, got {:?}", other),
+    }
+    assert_eq!(graph.negative_cycle_from(4), Ok(None));
+
+    // Node 4 cannot reach the cycle, so its distances are well defined.
+    assert_eq!(
+        graph.bellman_ford(4),
+        Ok((
+            vec![None, None, None, None, Some(0)],
+            vec![None, None, None, None, None]
+        ))
+    );
+
+    let cycle = graph.find_negative_cycle().unwrap();
+    assert_eq!(cycle.len(), 4);
+    assert_eq!(cycle.first(), cycle.last());
+    let total: i64 = cycle
+        .windows(2)
+        .map(|pair| {
+            *graph
+                .get_edges(pair[0])
+                .unwrap()
+                .into_iter()
+                .find(|(target, _)| *target == pair[1])
+                .unwrap()
+                .1
+        })
+        .sum();
+    assert_eq!(total, -2);
+}
+
+#[test]
+fn test_negative_cycle_from_ignores_unreachable_cycles() {
+    // The negative cycle 2 <-> 3 cannot be reached from node 0.
+    let mut g = DynamicGraph::new();
+    for _ in 0..4 {
+        g.add_node(());
+    }
+    g.add_edge(0, 1, 1).unwrap();
+    g.add_edge(2, 3, -2).unwrap();
+    g.add_edge(3, 2, 1).unwrap();
+    let graph = g.freeze();
+
+    assert_eq!(graph.negative_cycle_from(0), Ok(None));
+    assert!(graph.bellman_ford(0).is_ok());
+    assert!(graph.find_negative_cycle().is_some());
+
+    let cycle = graph.negative_cycle_from(2).unwrap().unwrap();
+    assert_eq!(cycle.len(), 3);
+    assert_eq!(cycle.first(), cycle.last());
+    assert_eq!(
+        graph.negative_cycle_from(99),
+        Err(GraphError::NodeNotFound(99))
+    );
+}
+
+#[test]
+fn test_bellman_ford_on_empty_graph() {
+    let graph = CsmGraph::<(), i32>::new();
+    assert_eq!(graph.find_negative_cycle(), None);
+    assert_eq!(graph.bellman_ford(0), Err(GraphError::NodeNotFound(0)));
+    assert_eq!(
+        graph.negative_cycle_from(0),
+        Err(GraphError::NodeNotFound(0))
+    );
+}
+
+#[test]
+fn test_find_negative_cycle_self_loop() {
+    let mut g = DynamicGraph::new();
+    let n0 = g.add_node(());
+    g.add_edge(n0, n0, -1.5).unwrap();
+    let graph = g.freeze();
+    assert_eq!(graph.find_negative_cycle(), Some(vec![0, 0]));
+}
+
+#[test]
+fn test_find_negative_cycle_positive_cycle() {
+    let mut g = DynamicGraph::new();
+    let n0 = g.add_node(());
+    let n1 = g.add_node(());
+    g.add_edge(n0, n1, 1).unwrap();
+    g.add_edge(n1, n0, 1).unwrap();
+    let graph = g.freeze();
+    assert!(graph.has_cycle());
+    assert_eq!(graph.find_negative_cycle(), None);
+}
+
+/// Creates a `width` x `height` grid where each node links to its right and lower
+/// neighbor. Moving right costs 1 and moving down costs 2.
+fn create_grid_graph(width: usize, height: usize) -> CsmGraph<(usize, usize), u32> {
+    let mut g = DynamicGraph::new();
+    for y in 0..height {
+        for x in 0..width {
+            g.add_node((x, y));
+        }
+    }
+    for y in 0..height {
+        for x in 0..width {
+            let index = y * width + x;
+            if x + 1 < width {
+                g.add_edge(index, index + 1, 1).unwrap();
+            }
+            if y + 1 < height {
+                g.add_edge(index, index + width, 2).unwrap();
+            }
+        }
+    }
+    g.freeze()
+}
+
+#[test]
+fn test_astar_on_grid() {
+    let graph = create_grid_graph(5, 4);
+    let goal = 19; // (4, 3)
+
+    // Weighted Manhattan distance is an admissible, consistent heuristic here.
+    let heuristic = |n: usize| {
+        let (x, y) = (n % 5, n / 5);
+        ((4 - x) + 2 * (3 - y)) as u32
+    };
+
+    let (path, cost) = graph.astar(0, goal, heuristic).unwrap();
+    assert_eq!(cost, 10);
+    assert_eq!(path.first(), Some(&0));
+    assert_eq!(path.last(), Some(&goal));
+    assert_eq!(path.len(), 8);
+    assert_eq!(graph.shortest_path_weighted(0, goal).unwrap().1, cost);
+}
+
+#[test]
+fn test_astar_zero_heuristic_matches_dijkstra() {
+    let graph = create_detour_graph();
+    assert_eq!(graph.astar(0, 2, |_| 0), graph.shortest_path_weighted(0, 2));
+
+    let graph = create_csm_graph();
+    assert_eq!(graph.astar(0, 4, |_| 0), Some((vec![0, 1, 3, 4], 90)));
+}
+
+#[test]
+fn test_astar_inconsistent_heuristic_is_still_optimal() {
+    // 0 -> 1 -> 3 costs 2, 0 -> 2 -> 3 costs 4. The heuristic is admissible but
+    // inconsistent, which makes node 1 look expensive at first.
+    let mut g = DynamicGraph::new();
+    for _ in 0..4 {
+        g.add_node(());
+    }
+    g.add_edge(0, 1, 1u32).unwrap();
+    g.add_edge(1, 3, 1).unwrap();
+    g.add_edge(0, 2, 2).unwrap();
+    g.add_edge(2, 3, 2).unwrap();
+    let graph = g.freeze();
+
+    let heuristic = |n: usize| if n == 1 { 1 } else { 0 };
+    assert_eq!(graph.astar(0, 3, heuristic), Some((vec![0, 1, 3], 2)));
+}
+
+#[test]
+fn test_astar_edge_cases() {
+    let graph = create_detour_graph();
+    assert_eq!(graph.astar(1, 1, |_| 0), Some((vec![1], 0)));
+    assert_eq!(graph.astar(0, 3, |_| 0), None); // No path
+    assert_eq!(graph.astar(2, 0, |_| 0), None); // No path
+    assert_eq!(graph.astar(0, 99, |_| 0), None); // Non-existent target
+    assert_eq!(graph.astar(99, 0, |_| 0), None); // Non-existent source
+}
@@ -0,0 +1,83 @@
+use next_graph::utils_test::gen_utils::create_csm_graph;
+use next_graph::{DynamicGraph, Freezable, GraphAlgorithms, GraphMut, TraversalWorkspace};
+
+#[test]
+fn test_workspace_variants_match_allocating_versions() {
+    // Edges: 0 -> 1, 0 -> 2, 1 -> 3, 2 -> 3, 3 -> 4
+    let graph = create_csm_graph();
+    let mut workspace = TraversalWorkspace::new();
+
+    for a in 0..6 {
+        for b in 0..6 {
+            assert_eq!(
+                graph.shortest_path_with_workspace(a, b, &mut workspace),
+                graph.shortest_path(a, b)
+            );
+            assert_eq!(
+                graph.shortest_path_len_with_workspace(a, b, &mut workspace),
+                graph.shortest_path_len(a, b)
+            );
+            assert_eq!(
+                graph.is_reachable_with_workspace(a, b, &mut workspace),
+                graph.is_reachable(a, b)
+            );
+        }
+    }
+    assert_eq!(graph.find_cycle_with_workspace(&mut workspace), None);
+}
+
+#[test]
+fn test_workspace_reuse_across_graphs() {
+    let mut workspace = TraversalWorkspace::with_capacity(2);
+    assert_eq!(workspace.capacity(), 2);
+
+    let dag = create_csm_graph();
+    assert_eq!(
+        dag.shortest_path_with_workspace(0, 4, &mut workspace),
+        Some(vec![0, 1, 3, 4])
+    );
+    // The workspace grows to fit the larger graph.
+    assert_eq!(workspace.capacity(), 5);
+
+    // Stale stamps from the previous graph must not leak into the next query.
+    let mut g = DynamicGraph::new();
+    for _ in 0..3 {
+        g.add_node(());
+    }
+    g.add_edge(0, 1, ()).unwrap();
+    g.add_edge(1, 2, ()).unwrap();
+    g.add_edge(2, 1, ()).unwrap();
+    let cyclic = g.freeze();
+
+    assert_eq!(
+        cyclic.find_cycle_with_workspace(&mut workspace),
+        Some(vec![1, 2, 1])
+    );
+    assert_eq!(
+        cyclic.shortest_path_with_workspace(0, 2, &mut workspace),
+        Some(vec![0, 1, 2])
+    );
+    assert_eq!(dag.find_cycle_with_workspace(&mut workspace), None);
+    assert_eq!(
+        dag.shortest_path_len_with_workspace(4, 0, &mut workspace),
+        None
+    );
+}
+
+#[test]
+fn test_find_cycle_self_loop_below_root() {
+    // A self-loop on a node that is reached from another node is reported as [n, n].
+    let mut g = DynamicGraph::new();
+    g.add_node(());
+    g.add_node(());
+    g.add_edge(0, 1, ()).unwrap();
+    g.add_edge(1, 1, ()).unwrap();
+    let graph = g.freeze();
+
+    let mut workspace = TraversalWorkspace::new();
+    assert_eq!(
+        graph.find_cycle_with_workspace(&mut workspace),
+        Some(vec![1, 1])
+    );
+    assert_eq!(graph.find_cycle(), Some(vec![1, 1]));
+}
@@ -0,0 +1,95 @@
+use next_graph::utils_test::gen_utils::create_csm_graph;
+use next_graph::{
+    CausalGraphAlgorithms, CsmGraph, DynamicGraph, Freezable, GraphMut, GraphTraversal, GraphView,
+    Unfreezable,
+};
+
+#[test]
+fn test_intervene_removes_incoming_edges() {
+    // Edges: 0 -> 1 (10), 0 -> 2 (20), 1 -> 3 (30), 2 -> 3 (40), 3 -> 4 (50)
+    let graph = create_csm_graph();
+    let mutilated = graph.intervene(&[3]);
+
+    assert_eq!(mutilated.number_nodes(), 5);
+    assert_eq!(mutilated.number_edges(), 3);
+    assert_eq!(mutilated.get_node(3), Some(&"D".to_string()));
+    assert!(!mutilated.contains_edge(1, 3));
+    assert!(!mutilated.contains_edge(2, 3));
+    assert_eq!(mutilated.get_edges(0), Some(vec![(1, &10), (2, &20)]));
+    assert_eq!(mutilated.get_edges(3), Some(vec![(4, &50)]));
+    assert_eq!(mutilated.inbound_edges(3).unwrap().count(), 0);
+    assert_eq!(mutilated.inbound_edges(4).unwrap().collect::<Vec<_>>(), [3]);
+
+    // The original graph is unchanged.
+    assert_eq!(graph.number_edges(), 5);
+    assert!(graph.contains_edge(1, 3));
+}
+
+/// Creates a confounded graph U -> X, U -> Y and two parallel edges X -> Y.
+fn create_confounded_graph() -> CsmGraph<&'static str, u32> {
+    let mut g = DynamicGraph::new();
+    let root = g.add_root_node("U");
+    let x = g.add_node("X");
+    let y = g.add_node("Y");
+    g.add_edge(root, x, 1).unwrap();
+    g.add_edge(root, y, 2).unwrap();
+    g.add_edge(x, y, 3).unwrap();
+    g.add_edge(x, y, 4).unwrap();
+    g.freeze()
+}
+
+#[test]
+fn test_intervene_matches_unfreeze_round_trip() {
+    let (root, x, y) = (0, 1, 2);
+    let graph = create_confounded_graph();
+    let mutilated = graph.intervene(&[x, y, x]);
+
+    let mut expected = create_confounded_graph().unfreeze();
+    expected.remove_edge(root, x).unwrap();
+    expected.remove_edge(root, y).unwrap();
+    expected.remove_edge(x, y).unwrap();
+    expected.remove_edge(x, y).unwrap();
+    let expected = expected.freeze();
+
+    assert_eq!(mutilated.number_edges(), expected.number_edges());
+    assert_eq!(mutilated.get_root_index(), Some(root));
+    for node in [root, x, y] {
+        assert_eq!(mutilated.get_node(node), expected.get_node(node));
+        assert_eq!(mutilated.get_edges(node), expected.get_edges(node));
+    }
+}
+
+#[test]
+fn test_intervene_breaks_confounding() {
+    // 0 confounds 1 and 2, and 1 causes 2.
+    let mut g = DynamicGraph::new();
+    for _ in 0..3 {
+        g.add_node(());
+    }
+    g.add_edge(0, 1, ()).unwrap();
+    g.add_edge(0, 2, ()).unwrap();
+    g.add_edge(1, 2, ()).unwrap();
+    let graph = g.freeze();
+    assert_eq!(graph.is_d_separated(&[0], &[1], &[]), Ok(false));
+
+    let mutilated = graph.intervene(&[1]);
+    assert_eq!(mutilated.is_d_separated(&[0], &[1], &[]), Ok(true));
+    assert_eq!(mutilated.backdoor_adjustment_set(1, 2), Ok(Some(vec![])));
+}
+
+#[test]
+fn test_intervene_edge_cases() {
+    let graph = create_csm_graph();
+    let copy = graph.intervene(&[]);
+    assert_eq!(copy.number_edges(), graph.number_edges());
+
+    let empty = DynamicGraph::<(), ()>::new().freeze();
+    assert_eq!(empty.intervene(&[]).number_nodes(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Cannot intervene on node 99: it does not exist.")]
+fn test_intervene_panics_on_missing_node() {
+    let graph = create_csm_graph();
+    let _ = graph.intervene(&[0, 99]);
+}
@@ -1,4 +1,10 @@
+mod graph_csm_algo_causal_tests;
+mod graph_csm_algo_dag_tests;
+mod graph_csm_algo_structural_tests;
 mod graph_csm_algo_tests;
+mod graph_csm_algo_weighted_tests;
+mod graph_csm_algo_workspace_tests;
+mod graph_csm_intervene_tests;
 mod graph_csm_tests;
 mod graph_csm_unfreeze_tests;
 mod graph_csm_view_tests;
@@ -0,0 +1,116 @@
+use next_graph::{DynamicGraph, Freezable, GraphAlgorithms, GraphMut};
+
+/// Creates the cyclic graph 0 -> 1 -> 2 -> 3 -> 1, with the shortcut 0 -> 3.
+fn create_cyclic_graph() -> DynamicGraph<(), ()> {
+    let mut g = DynamicGraph::new();
+    for _ in 0..4 {
+        g.add_node(());
+    }
+    g.add_edge(0, 1, ()).unwrap();
+    g.add_edge(1, 2, ()).unwrap();
+    g.add_edge(2, 3, ()).unwrap();
+    g.add_edge(3, 1, ()).unwrap();
+    g.add_edge(0, 3, ()).unwrap();
+    g
+}
+
+#[test]
+fn test_cycle_detection_while_mutating() {
+    let mut graph = create_cyclic_graph();
+    assert!(graph.has_cycle());
+    assert_eq!(graph.find_cycle(), Some(vec![1, 2, 3, 1]));
+    assert_eq!(graph.topological_sort(), None);
+
+    // Removing an edge of the cycle makes the graph a DAG again.
+    graph.remove_edge(3, 1).unwrap();
+    assert!(!graph.has_cycle());
+    assert_eq!(graph.find_cycle(), None);
+    assert_eq!(graph.topological_sort(), Some(vec![0, 1, 2, 3]));
+
+    // Removing a node on a cycle also breaks it.
+    graph.add_edge(3, 1, ()).unwrap();
+    assert!(graph.has_cycle());
+    graph.remove_node(2).unwrap();
+    assert!(!graph.has_cycle());
+    assert_eq!(graph.find_cycle(), None);
+}
+
+#[test]
+fn test_cycle_detection_on_high_degree_hub() {
+    // The cycle checks on a mutable graph must stay linear, like on the frozen graph.
+    let num_spokes = 200_000;
+    let mut graph = DynamicGraph::with_capacity(num_spokes + 1, None);
+    let hub = graph.add_node(());
+    for _ in 0..num_spokes {
+        let spoke = graph.add_node(());
+        graph.add_edge(hub, spoke, ()).unwrap();
+    }
+    assert!(!graph.has_cycle());
+
+    graph.add_edge(num_spokes, hub, ()).unwrap();
+    assert_eq!(graph.find_cycle(), Some(vec![hub, num_spokes, hub]));
+
+    // A tombstoned spoke no longer closes the cycle.
+    graph.remove_node(num_spokes).unwrap();
+    assert!(!graph.has_cycle());
+}
+
+#[test]
+fn test_topological_sort_uses_stable_indices() {
+    let mut graph = create_cyclic_graph();
+    graph.remove_node(1).unwrap();
+    // The removed node is skipped; all other indices are unchanged.
+    assert_eq!(graph.topological_sort(), Some(vec![0, 2, 3]));
+}
+
+#[test]
+fn test_topological_sort_by_key_skips_removed_nodes() {
+    let mut graph = DynamicGraph::new();
+    let c = graph.add_node('c');
+    let b = graph.add_node('b');
+    let a = graph.add_node('a');
+    let z = graph.add_node('z');
+    graph.add_edge(z, a, ()).unwrap();
+    assert_eq!(
+        graph.topological_sort_by_key(|&n| n),
+        Some(vec![b, c, z, a])
+    );
+
+    // Removing the node that blocks `a` makes it the first choice.
+    graph.remove_node(z).unwrap();
+    assert_eq!(graph.topological_sort_by_key(|&n| n), Some(vec![a, b, c]));
+    assert_eq!(
+        graph.topological_sort_by(|x, y| y.cmp(x)),
+        Some(vec![c, b, a])
+    );
+}
+
+#[test]
+fn test_pathfinding_ignores_removed_nodes() {
+    let mut graph = create_cyclic_graph();
+    assert_eq!(graph.shortest_path(0, 2), Some(vec![0, 1, 2]));
+    assert_eq!(graph.shortest_path_len(0, 2), Some(3));
+
+    graph.remove_node(1).unwrap();
+    assert_eq!(graph.shortest_path(0, 2), None);
+    assert!(graph.is_reachable(0, 3));
+    assert!(!graph.is_reachable(0, 1));
+    assert_eq!(graph.shortest_path(2, 2), Some(vec![2]));
+    assert_eq!(graph.shortest_path(0, 99), None);
+}
+
+#[test]
+fn test_algorithms_match_frozen_graph() {
+    let graph = create_cyclic_graph();
+    let frozen = create_cyclic_graph().freeze();
+
+    assert_eq!(graph.find_cycle(), frozen.find_cycle());
+    for a in 0..4 {
+        for b in 0..4 {
+            assert_eq!(
+                graph.shortest_path_len(a, b),
+                frozen.shortest_path_len(a, b)
+            );
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use next_graph::{Bfs, DynamicGraph, GraphError, GraphMut, GraphTraversal};
+
+/// Creates the graph 0 -> 1, 0 -> 2, 1 -> 2, 2 -> 3, 3 -> 1.
+fn create_graph() -> DynamicGraph<(), u32> {
+    let mut g = DynamicGraph::new();
+    for _ in 0..4 {
+        g.add_node(());
+    }
+    g.add_edge(0, 1, 1).unwrap();
+    g.add_edge(0, 2, 2).unwrap();
+    g.add_edge(1, 2, 3).unwrap();
+    g.add_edge(2, 3, 4).unwrap();
+    g.add_edge(3, 1, 5).unwrap();
+    g
+}
+
+fn sorted_inbound(graph: &DynamicGraph<(), u32>, a: usize) -> Vec<usize> {
+    let mut sources: Vec<usize> = graph.inbound_edges(a).unwrap().collect();
+    sources.sort_unstable();
+    sources
+}
+
+#[test]
+fn test_outbound_edges() {
+    let mut graph = create_graph();
+    assert_eq!(
+        graph.outbound_edges(0).unwrap().collect::<Vec<_>>(),
+        vec![1, 2]
+    );
+
+    // Edges to removed nodes are skipped, removed nodes are not found.
+    graph.remove_node(1).unwrap();
+    assert_eq!(
+        graph.outbound_edges(0).unwrap().collect::<Vec<_>>(),
+        vec![2]
+    );
+    assert_eq!(
+        graph.outbound_edges(1).err(),
+        Some(GraphError::NodeNotFound(1))
+    );
+    assert_eq!(
+        graph.outbound_edges(99).err(),
+        Some(GraphError::NodeNotFound(99))
+    );
+}
+
+#[test]
+fn test_inbound_edges_with_and_without_reverse_index() {
+    let mut scanned = create_graph();
+    let mut indexed = create_graph();
+    indexed.enable_reverse_index();
+    assert!(indexed.has_reverse_index());
+    assert!(!scanned.has_reverse_index());
+
+    // Apply the same mutations to both graphs.
+    for g in [&mut scanned, &mut indexed] {
+        let n4 = g.add_node(());
+        g.add_edge(n4, 2, 6).unwrap();
+        g.add_edge(n4, 2, 7).unwrap(); // Parallel edge
+        g.remove_edge(0, 2).unwrap();
+        g.remove_node(3).unwrap();
+    }
+
+    for a in [0, 1, 2, 4] {
+        assert_eq!(sorted_inbound(&scanned, a), sorted_inbound(&indexed, a));
+    }
+    assert_eq!(sorted_inbound(&indexed, 1), vec![0]);
+    assert_eq!(sorted_inbound(&indexed, 2), vec![1, 4, 4]);
+    assert_eq!(
+        indexed.inbound_edges(3).err(),
+        Some(GraphError::NodeNotFound(3))
+    );
+
+    // Disabling the index falls back to the scan.
+    indexed.disable_reverse_index();
+    assert_eq!(sorted_inbound(&indexed, 2), vec![1, 4, 4]);
+
+    // Clearing keeps an enabled index, but empties it.
+    scanned.enable_reverse_index();
+    scanned.clear();
+    let n0 = scanned.add_node(());
+    scanned.add_edge(n0, n0, 1).unwrap();
+    assert!(scanned.has_reverse_index());
+    assert_eq!(sorted_inbound(&scanned, n0), vec![n0]);
+}
+
+#[test]
+fn test_generic_traversal_on_dynamic_graph() {
+    let mut graph = create_graph();
+    assert_eq!(Bfs::new(&graph, 0).collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+
+    graph.remove_node(2).unwrap();
+    assert_eq!(Bfs::new(&graph, 0).collect::<Vec<_>>(), vec![0, 1]);
+}
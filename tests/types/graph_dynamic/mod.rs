@@ -1,8 +1,12 @@
 #[cfg(test)]
+mod graph_dyn_algo_tests;
+#[cfg(test)]
 mod graph_dyn_freeze_tests;
 #[cfg(test)]
 mod graph_dyn_mut_tests;
 #[cfg(test)]
 mod graph_dyn_tests;
 #[cfg(test)]
+mod graph_dyn_traversal_tests;
+#[cfg(test)]
 mod graph_dyn_view_tests;
@@ -0,0 +1,133 @@
+use next_graph::utils_test::gen_utils::create_hypergraph;
+use next_graph::{
+    DynamicHypergraph, GraphAlgorithms, GraphView, Hypergraph, HypergraphAlgorithms,
+    HypergraphFreezable, HypergraphMut,
+};
+
+// Hyperedges of `create_hypergraph`: e0 = {0, 1, 2}, e1 = {1, 2, 3}, e2 = {3, 4}.
+// Overlaps: |e0 ∩ e1| = 2, |e1 ∩ e2| = 1, |e0 ∩ e2| = 0.
+
+#[test]
+fn test_s_line_graph_s1() {
+    let graph = create_hypergraph();
+    let line_graph = graph.s_line_graph(1);
+
+    assert_eq!(line_graph.number_nodes(), 3);
+    assert_eq!(line_graph.number_edges(), 4); // Two undirected edges.
+    assert_eq!(line_graph.get_node(2), Some(&2));
+
+    assert!(line_graph.contains_edge(0, 1));
+    assert!(line_graph.contains_edge(1, 0));
+    assert!(line_graph.contains_edge(1, 2));
+    assert!(line_graph.contains_edge(2, 1));
+    assert!(!line_graph.contains_edge(0, 2));
+
+    // Edge weights are the overlap sizes.
+    assert_eq!(line_graph.get_edges(0), Some(vec![(1, &2)]));
+    assert_eq!(line_graph.get_edges(1), Some(vec![(0, &2), (2, &1)]));
+}
+
+#[test]
+fn test_s_line_graph_s2() {
+    let graph = create_hypergraph();
+    let line_graph = graph.s_line_graph(2);
+
+    assert_eq!(line_graph.number_nodes(), 3);
+    assert_eq!(line_graph.number_edges(), 2);
+    assert!(line_graph.contains_edge(0, 1));
+    assert!(!line_graph.contains_edge(1, 2));
+}
+
+#[test]
+fn test_s_line_graph_reuses_graph_algorithms() {
+    let graph = create_hypergraph();
+    let line_graph = graph.s_line_graph(1);
+
+    assert_eq!(line_graph.shortest_path(0, 2), Some(vec![0, 1, 2]));
+    assert!(line_graph.has_cycle()); // Undirected edges form 2-cycles.
+}
+
+#[test]
+fn test_s_line_graph_zero_is_one() {
+    let graph = create_hypergraph();
+    assert_eq!(
+        graph.s_line_graph(0).number_edges(),
+        graph.s_line_graph(1).number_edges()
+    );
+}
+
+#[test]
+fn test_s_line_graph_empty() {
+    let graph = Hypergraph::<(), ()>::new();
+    let line_graph = graph.s_line_graph(1);
+    assert_eq!(line_graph.number_nodes(), 0);
+    assert_eq!(line_graph.number_edges(), 0);
+}
+
+#[test]
+fn test_s_connected_components() {
+    let graph = create_hypergraph();
+
+    assert_eq!(graph.s_connected_components(1), vec![vec![0, 1, 2]]);
+    assert_eq!(graph.s_connected_components(2), vec![vec![0, 1], vec![2]]);
+    // e2 has only two nodes, so it cannot take part in any 3-walk.
+    assert_eq!(graph.s_connected_components(3), vec![vec![0], vec![1]]);
+    assert!(graph.s_connected_components(4).is_empty());
+}
+
+#[test]
+fn test_s_connected_components_disconnected() {
+    let mut dynamic_graph = DynamicHypergraph::new();
+    for _ in 0..6 {
+        dynamic_graph.add_node(());
+    }
+    dynamic_graph.add_hyperedge(&[3, 4], ()).unwrap();
+    dynamic_graph.add_hyperedge(&[0, 1], ()).unwrap();
+    dynamic_graph.add_hyperedge(&[4, 5], ()).unwrap();
+    dynamic_graph.add_hyperedge(&[1, 2], ()).unwrap();
+    let graph = dynamic_graph.freeze();
+
+    assert_eq!(
+        graph.s_connected_components(1),
+        vec![vec![0, 2], vec![1, 3]]
+    );
+}
+
+#[test]
+fn test_s_distance() {
+    let graph = create_hypergraph();
+
+    assert_eq!(graph.s_distance(1, 0, 0), Some(0));
+    assert_eq!(graph.s_distance(1, 0, 1), Some(1));
+    assert_eq!(graph.s_distance(1, 0, 2), Some(2));
+    assert_eq!(graph.s_distance(1, 2, 0), Some(2));
+    assert_eq!(graph.s_distance(2, 0, 1), Some(1));
+    assert_eq!(graph.s_distance(2, 0, 2), None);
+    assert_eq!(graph.s_distance(1, 0, 99), None);
+}
+
+#[test]
+fn test_s_distance_rejects_small_hyperedges() {
+    let graph = create_hypergraph();
+
+    // e2 has only two nodes, so it is not part of any 3-component.
+    assert_eq!(graph.s_distance(3, 2, 2), None);
+    assert_eq!(graph.s_distance(3, 0, 0), Some(0));
+    assert_eq!(graph.s_distance(3, 0, 1), None);
+    assert_eq!(graph.s_distance(0, 0, 2), graph.s_distance(1, 0, 2));
+
+    // The distance agrees with a shortest path query on the s-line graph.
+    for s in 1..4 {
+        let line_graph = graph.s_line_graph(s);
+        for source in 0..3 {
+            for target in 0..3 {
+                if source != target {
+                    assert_eq!(
+                        graph.s_distance(s, source, target),
+                        line_graph.shortest_path_len(source, target).map(|l| l - 1)
+                    );
+                }
+            }
+        }
+    }
+}
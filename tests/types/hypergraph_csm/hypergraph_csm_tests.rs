@@ -0,0 +1,51 @@
+use next_graph::utils_test::gen_utils::create_hypergraph;
+use next_graph::{Hypergraph, HypergraphView};
+
+#[test]
+fn test_new_hypergraph() {
+    let graph = Hypergraph::<String, u32>::new();
+    assert_eq!(graph.number_nodes(), 0);
+    assert_eq!(graph.number_hyperedges(), 0);
+    assert_eq!(graph.number_incidences(), 0);
+    assert!(graph.is_frozen());
+}
+
+#[test]
+fn test_default_hypergraph() {
+    let graph = Hypergraph::<String, u32>::default();
+    assert_eq!(graph.number_nodes(), 0);
+    assert_eq!(graph.number_hyperedges(), 0);
+    assert!(graph.is_frozen());
+}
+
+#[test]
+fn test_hypergraph_view() {
+    let graph = create_hypergraph();
+
+    assert_eq!(graph.number_nodes(), 5);
+    assert_eq!(graph.number_hyperedges(), 3);
+    assert_eq!(graph.number_incidences(), 8);
+
+    assert!(graph.contains_node(4));
+    assert!(!graph.contains_node(5));
+    assert_eq!(graph.get_node(0), Some(&"A".to_string()));
+    assert_eq!(graph.get_node(99), None);
+
+    assert!(graph.contains_hyperedge(2));
+    assert!(!graph.contains_hyperedge(3));
+    assert_eq!(graph.get_hyperedge(1), Some(&20));
+    assert_eq!(graph.get_hyperedge(99), None);
+}
+
+#[test]
+fn test_hypergraph_is_incident() {
+    let graph = create_hypergraph();
+
+    assert!(graph.is_incident(0, 0));
+    assert!(graph.is_incident(2, 1));
+    assert!(graph.is_incident(4, 2));
+    assert!(!graph.is_incident(0, 1));
+    assert!(!graph.is_incident(4, 0));
+    assert!(!graph.is_incident(0, 99)); // Non-existent hyperedge
+    assert!(!graph.is_incident(99, 0)); // Non-existent node
+}
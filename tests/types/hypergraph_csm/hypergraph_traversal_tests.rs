@@ -0,0 +1,37 @@
+use next_graph::utils_test::gen_utils::create_hypergraph;
+use next_graph::{GraphError, HypergraphTraversal};
+
+#[test]
+fn test_hyperedge_nodes() {
+    let graph = create_hypergraph();
+
+    let members: Vec<usize> = graph.hyperedge_nodes(0).unwrap().collect();
+    assert_eq!(members, vec![0, 1, 2]);
+
+    let members: Vec<usize> = graph.hyperedge_nodes(2).unwrap().collect();
+    assert_eq!(members, vec![3, 4]);
+
+    assert!(matches!(
+        graph.hyperedge_nodes(99),
+        Err(GraphError::HyperedgeNotFound(99))
+    ));
+}
+
+#[test]
+fn test_node_hyperedges() {
+    let graph = create_hypergraph();
+
+    let incident: Vec<usize> = graph.node_hyperedges(1).unwrap().collect();
+    assert_eq!(incident, vec![0, 1]);
+
+    let incident: Vec<usize> = graph.node_hyperedges(3).unwrap().collect();
+    assert_eq!(incident, vec![1, 2]);
+
+    let incident: Vec<usize> = graph.node_hyperedges(0).unwrap().collect();
+    assert_eq!(incident, vec![0]);
+
+    assert!(matches!(
+        graph.node_hyperedges(99),
+        Err(GraphError::NodeNotFound(99))
+    ));
+}
@@ -0,0 +1,50 @@
+use next_graph::utils_test::gen_utils::create_hypergraph;
+use next_graph::{
+    Hypergraph, HypergraphFreezable, HypergraphMut, HypergraphTraversal, HypergraphUnfreezable,
+    HypergraphView,
+};
+
+#[test]
+fn test_unfreeze_empty_hypergraph() {
+    let graph = Hypergraph::<String, u32>::new();
+    let dynamic_graph = graph.unfreeze();
+
+    assert_eq!(dynamic_graph.number_nodes(), 0);
+    assert_eq!(dynamic_graph.number_hyperedges(), 0);
+    assert!(!dynamic_graph.is_frozen());
+}
+
+#[test]
+fn test_unfreeze_preserves_structure() {
+    let graph = create_hypergraph();
+    let dynamic_graph = graph.unfreeze();
+
+    assert_eq!(dynamic_graph.number_nodes(), 5);
+    assert_eq!(dynamic_graph.number_hyperedges(), 3);
+    assert_eq!(dynamic_graph.number_incidences(), 8);
+    assert_eq!(dynamic_graph.get_node(3), Some(&"D".to_string()));
+    assert_eq!(dynamic_graph.get_hyperedge(2), Some(&30));
+
+    let members: Vec<usize> = dynamic_graph.hyperedge_nodes(1).unwrap().collect();
+    assert_eq!(members, vec![1, 2, 3]);
+}
+
+#[test]
+fn test_unfreeze_mutate_refreeze() {
+    let graph = create_hypergraph();
+    let mut dynamic_graph = graph.unfreeze();
+
+    let n5 = dynamic_graph.add_node("F".to_string());
+    let e3 = dynamic_graph.add_hyperedge(&[0, 4, n5], 40).unwrap();
+    dynamic_graph.remove_hyperedge(1).unwrap();
+
+    let graph = dynamic_graph.freeze();
+    assert_eq!(graph.number_nodes(), 6);
+    assert_eq!(graph.number_hyperedges(), 3);
+
+    // Hyperedge 1 was removed, so the new hyperedge shifts down to index 2.
+    assert_eq!(e3, 3);
+    assert_eq!(graph.get_hyperedge(2), Some(&40));
+    let members: Vec<usize> = graph.hyperedge_nodes(2).unwrap().collect();
+    assert_eq!(members, vec![0, 4, 5]);
+}
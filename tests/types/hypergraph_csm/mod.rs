@@ -0,0 +1,4 @@
+mod hypergraph_algo_tests;
+mod hypergraph_csm_tests;
+mod hypergraph_traversal_tests;
+mod hypergraph_unfreeze_tests;
@@ -0,0 +1,33 @@
+use next_graph::utils_test::gen_utils::create_directed_hypergraph;
+use next_graph::{DirectedHypergraph, HypergraphView};
+
+#[test]
+fn test_new_directed_hypergraph() {
+    let graph = DirectedHypergraph::<String, u32>::new();
+    assert_eq!(graph.number_nodes(), 0);
+    assert_eq!(graph.number_hyperedges(), 0);
+    assert_eq!(graph.number_incidences(), 0);
+    assert!(graph.is_frozen());
+
+    let graph = DirectedHypergraph::<String, u32>::default();
+    assert_eq!(graph.number_nodes(), 0);
+}
+
+#[test]
+fn test_directed_hypergraph_view() {
+    let graph = create_directed_hypergraph();
+
+    assert_eq!(graph.number_nodes(), 5);
+    assert_eq!(graph.number_hyperedges(), 3);
+    assert_eq!(graph.number_incidences(), 9);
+    assert_eq!(graph.get_node(2), Some(&"C".to_string()));
+    assert_eq!(graph.get_hyperedge(0), Some(&10));
+    assert!(!graph.contains_hyperedge(3));
+
+    // Incidence covers both the tail and the head.
+    assert!(graph.is_incident(0, 0));
+    assert!(graph.is_incident(3, 0));
+    assert!(graph.is_incident(0, 2));
+    assert!(!graph.is_incident(4, 0));
+    assert!(!graph.is_incident(0, 99));
+}
@@ -0,0 +1,78 @@
+use next_graph::utils_test::gen_utils::create_directed_hypergraph;
+use next_graph::{DirectedHypergraphTraversal, GraphError};
+
+#[test]
+fn test_hyperedge_tail_and_head() {
+    let graph = create_directed_hypergraph();
+
+    let tail: Vec<usize> = graph.hyperedge_tail(0).unwrap().collect();
+    let head: Vec<usize> = graph.hyperedge_head(0).unwrap().collect();
+    assert_eq!(tail, vec![0, 1]);
+    assert_eq!(head, vec![2, 3]);
+
+    assert!(matches!(
+        graph.hyperedge_tail(99),
+        Err(GraphError::HyperedgeNotFound(99))
+    ));
+    assert!(matches!(
+        graph.hyperedge_head(99),
+        Err(GraphError::HyperedgeNotFound(99))
+    ));
+}
+
+#[test]
+fn test_outbound_hyperedges() {
+    let graph = create_directed_hypergraph();
+
+    let out: Vec<usize> = graph.outbound_hyperedges(0).unwrap().collect();
+    assert_eq!(out, vec![0]);
+    let out: Vec<usize> = graph.outbound_hyperedges(4).unwrap().collect();
+    assert_eq!(out, vec![2]);
+    let out: Vec<usize> = graph.outbound_hyperedges(2).unwrap().collect();
+    assert_eq!(out, vec![1]);
+
+    assert!(matches!(
+        graph.outbound_hyperedges(99),
+        Err(GraphError::NodeNotFound(99))
+    ));
+}
+
+#[test]
+fn test_inbound_hyperedges() {
+    let graph = create_directed_hypergraph();
+
+    let inbound: Vec<usize> = graph.inbound_hyperedges(0).unwrap().collect();
+    assert_eq!(inbound, vec![2]);
+    let inbound: Vec<usize> = graph.inbound_hyperedges(3).unwrap().collect();
+    assert_eq!(inbound, vec![0]);
+    let inbound: Vec<usize> = graph.inbound_hyperedges(1).unwrap().collect();
+    assert!(inbound.is_empty());
+
+    assert!(matches!(
+        graph.inbound_hyperedges(99),
+        Err(GraphError::NodeNotFound(99))
+    ));
+}
+
+#[test]
+fn test_forward_and_backward_traversal() {
+    let graph = create_directed_hypergraph();
+
+    // Forward: everything B jointly causes, one hyperedge step away.
+    let mut successors: Vec<usize> = graph
+        .outbound_hyperedges(1)
+        .unwrap()
+        .flat_map(|e| graph.hyperedge_head(e).unwrap())
+        .collect();
+    successors.sort_unstable();
+    assert_eq!(successors, vec![2, 3]);
+
+    // Backward: the direct causes of A.
+    let mut predecessors: Vec<usize> = graph
+        .inbound_hyperedges(0)
+        .unwrap()
+        .flat_map(|e| graph.hyperedge_tail(e).unwrap())
+        .collect();
+    predecessors.sort_unstable();
+    assert_eq!(predecessors, vec![3, 4]);
+}
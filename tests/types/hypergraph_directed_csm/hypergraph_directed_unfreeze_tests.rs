@@ -0,0 +1,34 @@
+use next_graph::utils_test::gen_utils::create_directed_hypergraph;
+use next_graph::{
+    DirectedHypergraphFreezable, DirectedHypergraphMut, DirectedHypergraphTraversal,
+    DirectedHypergraphUnfreezable, HypergraphView,
+};
+
+#[test]
+fn test_unfreeze_preserves_structure() {
+    let graph = create_directed_hypergraph();
+    let dynamic_graph = graph.unfreeze();
+
+    assert!(!dynamic_graph.is_frozen());
+    assert_eq!(dynamic_graph.number_nodes(), 5);
+    assert_eq!(dynamic_graph.number_hyperedges(), 3);
+    assert_eq!(dynamic_graph.number_incidences(), 9);
+
+    let tail: Vec<usize> = dynamic_graph.hyperedge_tail(2).unwrap().collect();
+    let head: Vec<usize> = dynamic_graph.hyperedge_head(2).unwrap().collect();
+    assert_eq!(tail, vec![3, 4]);
+    assert_eq!(head, vec![0]);
+}
+
+#[test]
+fn test_unfreeze_mutate_refreeze() {
+    let mut dynamic_graph = create_directed_hypergraph().unfreeze();
+    dynamic_graph.remove_hyperedge(0).unwrap();
+    dynamic_graph.add_hyperedge(&[0], &[1, 2], 40).unwrap();
+
+    let graph = dynamic_graph.freeze();
+    assert_eq!(graph.number_hyperedges(), 3);
+    assert_eq!(graph.get_hyperedge(2), Some(&40));
+    let out: Vec<usize> = graph.outbound_hyperedges(0).unwrap().collect();
+    assert_eq!(out, vec![2]);
+}
@@ -0,0 +1,3 @@
+mod hypergraph_directed_csm_tests;
+mod hypergraph_directed_traversal_tests;
+mod hypergraph_directed_unfreeze_tests;
@@ -0,0 +1,43 @@
+use next_graph::{
+    DirectedHypergraphFreezable, DirectedHypergraphMut, DirectedHypergraphTraversal,
+    DynamicDirectedHypergraph, HypergraphView,
+};
+
+#[test]
+fn test_freeze_empty_directed_hypergraph() {
+    let graph = DynamicDirectedHypergraph::<String, u32>::new().freeze();
+    assert_eq!(graph.number_nodes(), 0);
+    assert_eq!(graph.number_hyperedges(), 0);
+    assert!(graph.is_frozen());
+}
+
+#[test]
+fn test_freeze_compacts_tombstones() {
+    let mut dynamic_graph = DynamicDirectedHypergraph::new();
+    let a = dynamic_graph.add_node("A".to_string());
+    let b = dynamic_graph.add_node("B".to_string());
+    let c = dynamic_graph.add_node("C".to_string());
+    let d = dynamic_graph.add_node("D".to_string());
+    let e0 = dynamic_graph.add_hyperedge(&[a], &[b], 10).unwrap();
+    dynamic_graph.add_hyperedge(&[a, b], &[c, d], 20).unwrap();
+    dynamic_graph.add_hyperedge(&[d], &[a], 30).unwrap();
+
+    dynamic_graph.remove_node(b).unwrap();
+    dynamic_graph.remove_hyperedge(e0).unwrap();
+
+    let graph = dynamic_graph.freeze();
+    assert_eq!(graph.number_nodes(), 3);
+    assert_eq!(graph.number_hyperedges(), 2);
+    assert_eq!(graph.number_incidences(), 5);
+
+    // Old nodes [a, c, d] are now [0, 1, 2].
+    let tail: Vec<usize> = graph.hyperedge_tail(0).unwrap().collect();
+    let head: Vec<usize> = graph.hyperedge_head(0).unwrap().collect();
+    assert_eq!(tail, vec![0]);
+    assert_eq!(head, vec![1, 2]);
+
+    let out: Vec<usize> = graph.outbound_hyperedges(2).unwrap().collect();
+    assert_eq!(out, vec![1]);
+    let inbound: Vec<usize> = graph.inbound_hyperedges(0).unwrap().collect();
+    assert_eq!(inbound, vec![1]);
+}
@@ -0,0 +1,75 @@
+use next_graph::{
+    DirectedHypergraphMut, DirectedHypergraphTraversal, DynamicDirectedHypergraph, GraphError,
+    HypergraphView,
+};
+
+#[test]
+fn test_add_directed_hyperedge() {
+    let mut graph = DynamicDirectedHypergraph::<String, u32>::new();
+    let a = graph.add_node("A".to_string());
+    let b = graph.add_node("B".to_string());
+    let c = graph.add_node("C".to_string());
+
+    let e0 = graph.add_hyperedge(&[b, a, a], &[c], 10).unwrap();
+    assert_eq!(e0, 0);
+    assert_eq!(graph.number_hyperedges(), 1);
+    assert_eq!(graph.number_incidences(), 3);
+
+    let tail: Vec<usize> = graph.hyperedge_tail(e0).unwrap().collect();
+    assert_eq!(tail, vec![a, b]);
+    let out: Vec<usize> = graph.outbound_hyperedges(a).unwrap().collect();
+    assert_eq!(out, vec![e0]);
+    let inbound: Vec<usize> = graph.inbound_hyperedges(c).unwrap().collect();
+    assert_eq!(inbound, vec![e0]);
+
+    assert_eq!(
+        graph.add_hyperedge(&[a], &[99], 20),
+        Err(GraphError::NodeNotFound(99))
+    );
+    assert_eq!(graph.number_hyperedges(), 1);
+}
+
+#[test]
+fn test_node_and_hyperedge_removal() {
+    let mut graph = DynamicDirectedHypergraph::<String, u32>::with_capacity(3, 2);
+    let a = graph.add_node("A".to_string());
+    let b = graph.add_node("B".to_string());
+    let c = graph.add_node("C".to_string());
+    let e0 = graph.add_hyperedge(&[a, b], &[c], 10).unwrap();
+    let e1 = graph.add_hyperedge(&[c], &[a], 20).unwrap();
+
+    graph.remove_node(b).unwrap();
+    assert_eq!(graph.remove_node(b), Err(GraphError::NodeNotFound(b)));
+    let tail: Vec<usize> = graph.hyperedge_tail(e0).unwrap().collect();
+    assert_eq!(tail, vec![a]);
+    assert!(!graph.is_incident(b, e0));
+
+    graph.remove_hyperedge(e1).unwrap();
+    assert_eq!(
+        graph.remove_hyperedge(e1),
+        Err(GraphError::HyperedgeNotFound(e1))
+    );
+    let out: Vec<usize> = graph.outbound_hyperedges(c).unwrap().collect();
+    assert!(out.is_empty());
+    assert_eq!(graph.number_incidences(), 2);
+}
+
+#[test]
+fn test_update_and_clear() {
+    let mut graph = DynamicDirectedHypergraph::<String, u32>::default();
+    let a = graph.add_node("A".to_string());
+    let e0 = graph.add_hyperedge(&[a], &[a], 10).unwrap();
+
+    assert!(graph.update_node(a, "Z".to_string()).is_ok());
+    assert_eq!(graph.get_node(a), Some(&"Z".to_string()));
+    assert!(graph.update_hyperedge(e0, 11).is_ok());
+    assert_eq!(graph.get_hyperedge(e0), Some(&11));
+    assert_eq!(
+        graph.update_hyperedge(99, 0),
+        Err(GraphError::HyperedgeNotFound(99))
+    );
+
+    graph.clear();
+    assert_eq!(graph.number_nodes(), 0);
+    assert_eq!(graph.number_hyperedges(), 0);
+}
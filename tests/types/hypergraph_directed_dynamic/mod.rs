@@ -0,0 +1,4 @@
+#[cfg(test)]
+mod hypergraph_directed_dyn_freeze_tests;
+#[cfg(test)]
+mod hypergraph_directed_dyn_mut_tests;
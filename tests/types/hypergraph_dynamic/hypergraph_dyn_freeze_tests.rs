@@ -0,0 +1,60 @@
+use next_graph::{
+    DynamicHypergraph, HypergraphFreezable, HypergraphMut, HypergraphTraversal, HypergraphView,
+};
+
+#[test]
+fn test_freeze_empty_hypergraph() {
+    let graph = DynamicHypergraph::<String, u32>::new().freeze();
+    assert_eq!(graph.number_nodes(), 0);
+    assert_eq!(graph.number_hyperedges(), 0);
+    assert!(graph.is_frozen());
+}
+
+#[test]
+fn test_freeze_builds_both_incidence_directions() {
+    let mut dynamic_graph = DynamicHypergraph::new();
+    let n0 = dynamic_graph.add_node("A".to_string());
+    let n1 = dynamic_graph.add_node("B".to_string());
+    let n2 = dynamic_graph.add_node("C".to_string());
+    dynamic_graph.add_hyperedge(&[n2, n0], 10).unwrap();
+    dynamic_graph.add_hyperedge(&[n1, n2], 20).unwrap();
+
+    let graph = dynamic_graph.freeze();
+    assert_eq!(graph.number_incidences(), 4);
+
+    let members: Vec<usize> = graph.hyperedge_nodes(0).unwrap().collect();
+    assert_eq!(members, vec![0, 2]);
+    let incident: Vec<usize> = graph.node_hyperedges(2).unwrap().collect();
+    assert_eq!(incident, vec![0, 1]);
+    let incident: Vec<usize> = graph.node_hyperedges(1).unwrap().collect();
+    assert_eq!(incident, vec![1]);
+}
+
+#[test]
+fn test_freeze_compacts_tombstones() {
+    let mut dynamic_graph = DynamicHypergraph::new();
+    let n0 = dynamic_graph.add_node("A".to_string());
+    let n1 = dynamic_graph.add_node("B".to_string());
+    let n2 = dynamic_graph.add_node("C".to_string());
+    let n3 = dynamic_graph.add_node("D".to_string());
+    let e0 = dynamic_graph.add_hyperedge(&[n0, n1], 10).unwrap();
+    dynamic_graph.add_hyperedge(&[n1, n2, n3], 20).unwrap();
+    dynamic_graph.add_hyperedge(&[n0, n3], 30).unwrap();
+
+    dynamic_graph.remove_node(n1).unwrap();
+    dynamic_graph.remove_hyperedge(e0).unwrap();
+
+    let graph = dynamic_graph.freeze();
+    assert_eq!(graph.number_nodes(), 3);
+    assert_eq!(graph.number_hyperedges(), 2);
+    assert_eq!(graph.get_node(1), Some(&"C".to_string()));
+    assert_eq!(graph.get_hyperedge(0), Some(&20));
+
+    // Old nodes [2, 3] are now [1, 2]; old hyperedge 2 is now 1.
+    let members: Vec<usize> = graph.hyperedge_nodes(0).unwrap().collect();
+    assert_eq!(members, vec![1, 2]);
+    let members: Vec<usize> = graph.hyperedge_nodes(1).unwrap().collect();
+    assert_eq!(members, vec![0, 2]);
+    let incident: Vec<usize> = graph.node_hyperedges(2).unwrap().collect();
+    assert_eq!(incident, vec![0, 1]);
+}
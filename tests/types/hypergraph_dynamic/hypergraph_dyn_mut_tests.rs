@@ -0,0 +1,113 @@
+use next_graph::{DynamicHypergraph, GraphError, HypergraphMut, HypergraphView};
+
+#[test]
+fn test_add_node() {
+    let mut graph = DynamicHypergraph::<String, u32>::new();
+    assert_eq!(graph.add_node("A".to_string()), 0);
+    assert_eq!(graph.add_node("B".to_string()), 1);
+    assert_eq!(graph.number_nodes(), 2);
+    assert_eq!(graph.get_node(1), Some(&"B".to_string()));
+}
+
+#[test]
+fn test_update_node() {
+    let mut graph = DynamicHypergraph::<String, u32>::new();
+    let n0 = graph.add_node("A".to_string());
+
+    assert!(graph.update_node(n0, "Z".to_string()).is_ok());
+    assert_eq!(graph.get_node(n0), Some(&"Z".to_string()));
+    assert_eq!(
+        graph.update_node(99, "X".to_string()),
+        Err(GraphError::NodeNotFound(99))
+    );
+
+    graph.remove_node(n0).unwrap();
+    assert_eq!(
+        graph.update_node(n0, "X".to_string()),
+        Err(GraphError::NodeNotFound(n0))
+    );
+}
+
+#[test]
+fn test_remove_node() {
+    let mut graph = DynamicHypergraph::<String, u32>::new();
+    let n0 = graph.add_node("A".to_string());
+    let n1 = graph.add_node("B".to_string());
+    let e0 = graph.add_hyperedge(&[n0, n1], 10).unwrap();
+
+    assert!(graph.remove_node(n0).is_ok());
+    assert!(!graph.contains_node(n0));
+    assert_eq!(graph.number_nodes(), 1);
+    // The hyperedge survives, but the removed node is no longer a member.
+    assert!(graph.contains_hyperedge(e0));
+    assert!(!graph.is_incident(n0, e0));
+    assert!(graph.is_incident(n1, e0));
+    assert_eq!(graph.number_incidences(), 1);
+
+    assert_eq!(graph.remove_node(n0), Err(GraphError::NodeNotFound(n0)));
+    assert_eq!(graph.remove_node(99), Err(GraphError::NodeNotFound(99)));
+}
+
+#[test]
+fn test_add_hyperedge() {
+    let mut graph = DynamicHypergraph::<String, u32>::new();
+    let n0 = graph.add_node("A".to_string());
+    let n1 = graph.add_node("B".to_string());
+    let n2 = graph.add_node("C".to_string());
+
+    let e0 = graph.add_hyperedge(&[n2, n0, n1, n0], 10).unwrap();
+    assert_eq!(e0, 0);
+    assert_eq!(graph.number_hyperedges(), 1);
+    // Duplicate memberships are collapsed.
+    assert_eq!(graph.number_incidences(), 3);
+
+    // An empty hyperedge is allowed.
+    let e1 = graph.add_hyperedge(&[], 20).unwrap();
+    assert_eq!(e1, 1);
+    assert_eq!(graph.number_hyperedges(), 2);
+
+    // Invalid member leaves the hypergraph unchanged.
+    assert_eq!(
+        graph.add_hyperedge(&[n0, 99], 30),
+        Err(GraphError::NodeNotFound(99))
+    );
+    assert_eq!(graph.number_hyperedges(), 2);
+}
+
+#[test]
+fn test_update_and_remove_hyperedge() {
+    let mut graph = DynamicHypergraph::<String, u32>::new();
+    let n0 = graph.add_node("A".to_string());
+    let e0 = graph.add_hyperedge(&[n0], 10).unwrap();
+
+    assert!(graph.update_hyperedge(e0, 11).is_ok());
+    assert_eq!(graph.get_hyperedge(e0), Some(&11));
+    assert_eq!(
+        graph.update_hyperedge(99, 0),
+        Err(GraphError::HyperedgeNotFound(99))
+    );
+
+    assert!(graph.remove_hyperedge(e0).is_ok());
+    assert!(!graph.contains_hyperedge(e0));
+    assert_eq!(graph.number_incidences(), 0);
+    assert_eq!(
+        graph.remove_hyperedge(e0),
+        Err(GraphError::HyperedgeNotFound(e0))
+    );
+    assert_eq!(
+        graph.update_hyperedge(e0, 0),
+        Err(GraphError::HyperedgeNotFound(e0))
+    );
+}
+
+#[test]
+fn test_clear() {
+    let mut graph = DynamicHypergraph::<String, u32>::new();
+    let n0 = graph.add_node("A".to_string());
+    graph.add_hyperedge(&[n0], 10).unwrap();
+
+    graph.clear();
+    assert_eq!(graph.number_nodes(), 0);
+    assert_eq!(graph.number_hyperedges(), 0);
+    assert_eq!(graph.add_node("B".to_string()), 0);
+}
@@ -0,0 +1,57 @@
+use next_graph::{
+    DynamicHypergraph, GraphError, HypergraphMut, HypergraphTraversal, HypergraphView,
+};
+
+fn create_dynamic_hypergraph() -> DynamicHypergraph<String, u32> {
+    let mut graph = DynamicHypergraph::with_capacity(4, 2);
+    let n0 = graph.add_node("A".to_string());
+    let n1 = graph.add_node("B".to_string());
+    let n2 = graph.add_node("C".to_string());
+    let n3 = graph.add_node("D".to_string());
+    graph.add_hyperedge(&[n0, n1, n2], 10).unwrap();
+    graph.add_hyperedge(&[n2, n3], 20).unwrap();
+    graph
+}
+
+#[test]
+fn test_new_dynamic_hypergraph() {
+    let graph = DynamicHypergraph::<String, u32>::default();
+    assert_eq!(graph.number_nodes(), 0);
+    assert_eq!(graph.number_hyperedges(), 0);
+    assert!(!graph.is_frozen());
+}
+
+#[test]
+fn test_dynamic_hypergraph_view() {
+    let graph = create_dynamic_hypergraph();
+    assert_eq!(graph.number_nodes(), 4);
+    assert_eq!(graph.number_hyperedges(), 2);
+    assert_eq!(graph.number_incidences(), 5);
+    assert!(graph.is_incident(2, 0));
+    assert!(graph.is_incident(2, 1));
+    assert!(!graph.is_incident(3, 0));
+}
+
+#[test]
+fn test_dynamic_hypergraph_traversal() {
+    let mut graph = create_dynamic_hypergraph();
+
+    let incident: Vec<usize> = graph.node_hyperedges(2).unwrap().collect();
+    assert_eq!(incident, vec![0, 1]);
+
+    graph.remove_node(1).unwrap();
+    let members: Vec<usize> = graph.hyperedge_nodes(0).unwrap().collect();
+    assert_eq!(members, vec![0, 2]);
+    assert!(matches!(
+        graph.node_hyperedges(1),
+        Err(GraphError::NodeNotFound(1))
+    ));
+
+    graph.remove_hyperedge(0).unwrap();
+    let incident: Vec<usize> = graph.node_hyperedges(2).unwrap().collect();
+    assert_eq!(incident, vec![1]);
+    assert!(matches!(
+        graph.hyperedge_nodes(0),
+        Err(GraphError::HyperedgeNotFound(0))
+    ));
+}
@@ -0,0 +1,6 @@
+#[cfg(test)]
+mod hypergraph_dyn_freeze_tests;
+#[cfg(test)]
+mod hypergraph_dyn_mut_tests;
+#[cfg(test)]
+mod hypergraph_dyn_view_tests;
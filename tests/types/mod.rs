@@ -1,2 +1,10 @@
+mod dominator_tree;
 mod graph_csm;
 mod graph_dynamic;
+mod hypergraph_csm;
+mod hypergraph_directed_csm;
+mod hypergraph_directed_dynamic;
+mod hypergraph_dynamic;
+mod reachability_index;
+mod transitive_closure;
+mod traversal;
@@ -0,0 +1 @@
+mod reachability_index_tests;
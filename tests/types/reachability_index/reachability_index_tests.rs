@@ -0,0 +1,76 @@
+use next_graph::utils_test::gen_utils::create_csm_graph;
+use next_graph::{
+    CsmGraph, DynamicGraph, Freezable, GraphAlgorithms, GraphMut, GraphView, ReachabilityIndex,
+};
+
+/// Creates a pseudo-random graph with feedback loops, long chains and isolated nodes.
+fn create_mixed_graph(num_nodes: usize) -> CsmGraph<(), ()> {
+    let mut g = DynamicGraph::new();
+    for _ in 0..num_nodes {
+        g.add_node(());
+    }
+    for i in 0..num_nodes {
+        if i % 11 == 5 {
+            continue; // Leave some nodes without outgoing edges.
+        }
+        g.add_edge(i, (i * 7 + 3) % num_nodes, ()).unwrap();
+        if i % 3 == 0 {
+            g.add_edge(i, (i + 1) % num_nodes, ()).unwrap();
+        }
+        if i % 17 == 0 {
+            g.add_edge(i, i / 2, ()).unwrap();
+        }
+    }
+    g.freeze()
+}
+
+fn assert_matches_bfs(graph: &CsmGraph<(), ()>, index: &ReachabilityIndex) {
+    let n = graph.number_nodes();
+    for a in 0..n {
+        for b in 0..n {
+            assert_eq!(
+                index.is_reachable(a, b),
+                graph.is_reachable(a, b),
+                "{a} -> {b}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_reachability_on_dag() {
+    // Edges: 0 -> 1, 0 -> 2, 1 -> 3, 2 -> 3, 3 -> 4
+    let graph = create_csm_graph();
+    let index = ReachabilityIndex::new(&graph);
+    assert_eq!(index.number_nodes(), 5);
+    assert_eq!(index.number_components(), 5);
+
+    assert!(index.is_reachable(0, 4));
+    assert!(index.is_reachable(2, 3));
+    assert!(!index.is_reachable(1, 2));
+    assert!(!index.is_reachable(4, 0));
+    assert!(index.is_reachable(3, 3));
+}
+
+#[test]
+fn test_reachability_matches_bfs_on_cyclic_graph() {
+    let graph = create_mixed_graph(120);
+    for k in [0, 1, 2, 5] {
+        let index = ReachabilityIndex::with_num_labels(&graph, k);
+        assert_matches_bfs(&graph, &index);
+    }
+}
+
+#[test]
+fn test_reachability_invalid_and_empty() {
+    let graph = create_csm_graph();
+    let index = ReachabilityIndex::new(&graph);
+    assert!(!index.is_reachable(0, 99));
+    assert!(!index.is_reachable(99, 0));
+    assert!(!index.is_reachable(99, 99));
+
+    let empty = DynamicGraph::<(), ()>::new().freeze();
+    let index = ReachabilityIndex::new(&empty);
+    assert_eq!(index.number_components(), 0);
+    assert!(!index.is_reachable(0, 0));
+}
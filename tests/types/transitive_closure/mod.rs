@@ -0,0 +1 @@
+mod transitive_closure_tests;
@@ -0,0 +1,31 @@
+use next_graph::utils_test::gen_utils::create_csm_graph;
+use next_graph::{DagAlgorithms, GraphError};
+
+#[test]
+fn test_is_reachable() {
+    // Edges: 0 -> 1, 0 -> 2, 1 -> 3, 2 -> 3, 3 -> 4
+    let closure = create_csm_graph().transitive_closure().unwrap();
+
+    assert!(closure.is_reachable(0, 4));
+    assert!(closure.is_reachable(1, 3));
+    assert!(!closure.is_reachable(1, 2));
+    assert!(!closure.is_reachable(4, 0));
+
+    // Every valid node reaches itself, invalid nodes reach nothing.
+    assert!(closure.is_reachable(2, 2));
+    assert!(!closure.is_reachable(0, 99));
+    assert!(!closure.is_reachable(99, 99));
+}
+
+#[test]
+fn test_reachable_from() {
+    let closure = create_csm_graph().transitive_closure().unwrap();
+
+    let from_root: Vec<usize> = closure.reachable_from(0).unwrap().collect();
+    assert_eq!(from_root, vec![1, 2, 3, 4]);
+    assert_eq!(closure.reachable_from(4).unwrap().count(), 0);
+    assert_eq!(
+        closure.reachable_from(5).err(),
+        Some(GraphError::NodeNotFound(5))
+    );
+}
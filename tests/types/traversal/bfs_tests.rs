@@ -0,0 +1,42 @@
+use next_graph::utils_test::gen_utils::create_csm_graph;
+use next_graph::{Bfs, DynamicGraph, Freezable, GraphMut};
+
+#[test]
+fn test_bfs_visiting_order() {
+    // Edges: 0 -> 1, 0 -> 2, 1 -> 3, 2 -> 3, 3 -> 4
+    let graph = create_csm_graph();
+
+    let order: Vec<usize> = Bfs::new(&graph, 0).collect();
+    assert_eq!(order, vec![0, 1, 2, 3, 4]);
+
+    let order: Vec<usize> = Bfs::new(&graph, 2).collect();
+    assert_eq!(order, vec![2, 3, 4]);
+}
+
+#[test]
+fn test_bfs_on_cycle_and_invalid_start() {
+    let mut g = DynamicGraph::new();
+    for _ in 0..3 {
+        g.add_node(());
+    }
+    g.add_edge(0, 1, ()).unwrap();
+    g.add_edge(1, 2, ()).unwrap();
+    g.add_edge(2, 0, ()).unwrap();
+    let graph = g.freeze();
+
+    // Every node is yielded exactly once, even on a cycle.
+    let order: Vec<usize> = Bfs::new(&graph, 1).collect();
+    assert_eq!(order, vec![1, 2, 0]);
+
+    assert_eq!(Bfs::new(&graph, 99).count(), 0);
+}
+
+#[test]
+fn test_bfs_is_lazy() {
+    let graph = create_csm_graph();
+    let mut bfs = Bfs::new(&graph, 0);
+    assert_eq!(bfs.next(), Some(0));
+    assert_eq!(bfs.next(), Some(1));
+    // The remaining nodes are still produced after partial consumption.
+    assert_eq!(bfs.collect::<Vec<_>>(), vec![2, 3, 4]);
+}
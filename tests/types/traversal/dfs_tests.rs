@@ -0,0 +1,116 @@
+use next_graph::utils_test::gen_utils::create_csm_graph;
+use next_graph::{
+    CsmGraph, Dfs, DfsEvent, DfsEvents, DynamicGraph, Freezable, GraphMut, GraphView,
+    depth_first_search,
+};
+use std::ops::ControlFlow;
+
+/// Creates the graph 0 -> 1 -> 2 -> 0 with the shortcut 0 -> 2 and an isolated node 3.
+fn create_cyclic_graph() -> CsmGraph<(), ()> {
+    let mut g = DynamicGraph::new();
+    for _ in 0..4 {
+        g.add_node(());
+    }
+    g.add_edge(0, 1, ()).unwrap();
+    g.add_edge(0, 2, ()).unwrap();
+    g.add_edge(1, 2, ()).unwrap();
+    g.add_edge(2, 0, ()).unwrap();
+    g.freeze()
+}
+
+#[test]
+fn test_dfs_discovery_order() {
+    // Edges: 0 -> 1, 0 -> 2, 1 -> 3, 2 -> 3, 3 -> 4
+    let graph = create_csm_graph();
+    let order: Vec<usize> = Dfs::new(&graph, 0).collect();
+    assert_eq!(order, vec![0, 1, 3, 4, 2]);
+
+    assert_eq!(Dfs::new(&graph, 99).count(), 0);
+}
+
+#[test]
+fn test_dfs_event_classification() {
+    use DfsEvent::*;
+
+    let graph = create_cyclic_graph();
+    let events: Vec<DfsEvent> = DfsEvents::new(&graph, 0..graph.number_nodes()).collect();
+    assert_eq!(
+        events,
+        vec![
+            Discover(0),
+            TreeEdge(0, 1),
+            Discover(1),
+            TreeEdge(1, 2),
+            Discover(2),
+            BackEdge(2, 0),
+            Finish(2),
+            Finish(1),
+            CrossEdge(0, 2),
+            Finish(0),
+            // The isolated node starts a new tree.
+            Discover(3),
+            Finish(3),
+        ]
+    );
+}
+
+#[test]
+fn test_dfs_events_consume_starts_lazily() {
+    // Collecting this range up front would need far more memory than available.
+    let graph = create_cyclic_graph();
+    let discovered: Vec<usize> = DfsEvents::new(&graph, 0..usize::MAX)
+        .filter_map(|event| match event {
+            DfsEvent::Discover(node) => Some(node),
+            _ => None,
+        })
+        .take(4)
+        .collect();
+    assert_eq!(discovered, vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn test_depth_first_search_visitor() {
+    let graph = create_cyclic_graph();
+
+    // Collect the finish order, which is a reverse topological order on DAGs.
+    let mut finished = Vec::new();
+    let result: ControlFlow<()> = depth_first_search(&graph, [1, 3], |event| {
+        if let DfsEvent::Finish(n) = event {
+            finished.push(n);
+        }
+        ControlFlow::Continue(())
+    });
+    assert_eq!(result, ControlFlow::Continue(()));
+    assert_eq!(finished, vec![0, 2, 1, 3]);
+
+    // Stop at the first back edge.
+    let result = depth_first_search(&graph, [0], |event| match event {
+        DfsEvent::BackEdge(u, v) => ControlFlow::Break((u, v)),
+        _ => ControlFlow::Continue(()),
+    });
+    assert_eq!(result, ControlFlow::Break((2, 0)));
+
+    // A DAG has no back edges.
+    let dag = create_csm_graph();
+    let result = depth_first_search(&dag, 0..dag.number_nodes(), |event| match event {
+        DfsEvent::BackEdge(..) => ControlFlow::Break(()),
+        _ => ControlFlow::Continue(()),
+    });
+    assert_eq!(result, ControlFlow::Continue(()));
+}
+
+#[test]
+fn test_dfs_deep_chain_does_not_overflow() {
+    let mut g = DynamicGraph::new();
+    let n = 100_000;
+    for _ in 0..n {
+        g.add_node(());
+    }
+    for i in 0..n - 1 {
+        g.add_edge(i, i + 1, ()).unwrap();
+    }
+    let graph = g.freeze();
+
+    assert_eq!(Dfs::new(&graph, 0).count(), n);
+    assert_eq!(Dfs::new(&graph, 0).last(), Some(n - 1));
+}
@@ -0,0 +1,2 @@
+mod bfs_tests;
+mod dfs_tests;